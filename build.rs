@@ -0,0 +1,183 @@
+//! Registers every solution in `src/solutions/year<year>/day<day>.rs`, so that adding a day is
+//! only a matter of creating its file.
+//!
+//! The generated `solutions.rs` declares the modules and the `register()` function, which
+//! `src/solutions/mod.rs` includes. A day's parts are the `part1` and `part2` functions it
+//! exports, taking the input or, if it exports a `parse` function, what that returns. Days without
+//! either, and second parts without a `part2`, are not registered, and day 25 only has a first
+//! part.
+//! Functions named `part1_<name>` or `part2_<name>` are registered as variants of a part, which
+//! take the same input. Only `pub` functions at the top level of the file count, wherever they
+//! are and however their signatures are laid out, as the file is parsed with `syn`.
+
+use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+/// The names of the `pub` functions that `file` declares at the top level.
+fn public_functions(file: &syn::File) -> Vec<String> {
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(function) if matches!(function.vis, syn::Visibility::Public(_)) => {
+                Some(function.sig.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+struct Day {
+    path: String,
+    parse: bool,
+    parts: [bool; 2],
+    variants: [Vec<String>; 2],
+}
+
+fn main() {
+    let solutions = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/solutions");
+    println!("cargo:rerun-if-changed={}", solutions.display());
+
+    let mut years = BTreeMap::<i32, BTreeMap<i32, Day>>::new();
+    for entry in std::fs::read_dir(&solutions).unwrap() {
+        let entry = entry.unwrap();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(year) = name.strip_prefix("year").and_then(|year| year.parse().ok()) else {
+            continue;
+        };
+        if !entry.file_type().unwrap().is_dir() {
+            continue;
+        }
+
+        let days = years.entry(year).or_default();
+        for entry in std::fs::read_dir(entry.path()).unwrap() {
+            let path = entry.unwrap().path();
+            let Some(day) = path
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day")?.strip_suffix(".rs"))
+                .and_then(|day| day.parse::<i32>().ok())
+                .filter(|day| (1..=25).contains(day))
+            else {
+                continue;
+            };
+
+            let source = std::fs::read_to_string(&path).unwrap();
+            let functions = match syn::parse_file(&source) {
+                Ok(file) => public_functions(&file),
+                Err(e) => {
+                    // Still declared below, so that the compiler reports the error in full.
+                    println!(
+                        "cargo:warning=could not parse `{}`, so none of its parts are \
+                         registered: {e}",
+                        path.display()
+                    );
+                    Vec::new()
+                }
+            };
+            let exports = |item: &str| functions.iter().any(|name| name == item);
+            let variants = |part: i32| {
+                let prefix = format!("part{part}_");
+                functions
+                    .iter()
+                    .filter_map(|name| name.strip_prefix(&prefix))
+                    .map(ToOwned::to_owned)
+                    .collect()
+            };
+            days.insert(
+                day,
+                Day {
+                    path: path.to_str().unwrap().to_owned(),
+                    parse: exports("parse"),
+                    parts: [exports("part1"), exports("part2")],
+                    variants: [variants(1), variants(2)],
+                },
+            );
+        }
+    }
+
+    let mut out = String::new();
+    for (year, days) in &years {
+        writeln!(out, "pub mod year{year} {{").unwrap();
+        for (day, solution) in days {
+            writeln!(
+                out,
+                "    #[path = {:?}]\n    pub mod day{day:02};",
+                solution.path
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    let year_list = years
+        .keys()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    writeln!(out, "pub const YEARS: &[i32] = &[{year_list}];\n").unwrap();
+    writeln!(
+        out,
+        "/// Registers every solution, including parts that are not implemented yet.\n\
+         fn register() -> Solutions {{\n    \
+         let mut sols = Solutions::default();"
+    )
+    .unwrap();
+    for (year, days) in &years {
+        for (day, solution) in days {
+            let module = format!("year{year}::day{day:02}");
+            let mut parts = solution.parts;
+            if *day == 25 && parts[1] {
+                println!(
+                    "cargo:warning=day 25 only has one part, so `{module}::part2` is not registered"
+                );
+                parts[1] = false;
+            }
+            if parts == [false, false] {
+                continue;
+            }
+
+            let part1 = if parts[0] {
+                format!("{module}::part1")
+            } else {
+                "unimplemented".to_owned()
+            };
+            let mut constructor = if parts[1] {
+                format!("Variants::new({part1}, {module}::part2)")
+            } else {
+                format!("Variants::single({part1})")
+            };
+            for part in 1..=2 {
+                for name in &solution.variants[part - 1] {
+                    if parts[part - 1] {
+                        write!(
+                            constructor,
+                            ".variant({part}, {name:?}, {module}::part{part}_{name})"
+                        )
+                        .unwrap();
+                    } else {
+                        println!(
+                            "cargo:warning=`{module}::part{part}_{name}` is not registered, as \
+                             there is no `part{part}` for it to be a variant of"
+                        );
+                    }
+                }
+            }
+
+            if solution.parse {
+                writeln!(
+                    out,
+                    "    sols.insert({year}, {day}, Day::with_parse_variants({module}::parse, \
+                     {constructor}));"
+                )
+            } else {
+                writeln!(
+                    out,
+                    "    sols.insert({year}, {day}, Day::from_variants({constructor}));"
+                )
+            }
+            .unwrap();
+        }
+    }
+    writeln!(out, "    sols\n}}").unwrap();
+
+    let path = Path::new(&std::env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    std::fs::write(path, out).unwrap();
+}
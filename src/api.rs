@@ -0,0 +1,231 @@
+use std::{fmt::Display, panic::AssertUnwindSafe};
+
+use crate::solutions::{solutions, Answer, Day, NoSuchSolution, SolveError, Variants};
+
+/// Why a part could not be run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    NoSuchSolution(NoSuchSolution),
+    NoSuchVariant {
+        year: i32,
+        day: i32,
+        part: i32,
+        variant: String,
+        /// The part's variants, starting with the default one.
+        expected: Vec<&'static str>,
+    },
+    /// The part is registered, but still returns `Unimplemented`.
+    NotImplemented {
+        year: i32,
+        day: i32,
+        part: i32,
+    },
+    /// The part found the input malformed.
+    Rejected(SolveError),
+    /// The part panicked, with the panic's message.
+    Panicked(String),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchSolution(e) => write!(f, "{e}"),
+            Self::NoSuchVariant {
+                year,
+                day,
+                part,
+                variant,
+                expected,
+            } => write!(
+                f,
+                "{year} day {day} part {part} has no variant `{variant}`, expected one of {}",
+                expected.join(", ")
+            ),
+            Self::NotImplemented { year, day, part } => {
+                write!(f, "{year} day {day} part {part} is not implemented")
+            }
+            Self::Rejected(e) => write!(f, "rejected the input: {e}"),
+            Self::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for RunError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NoSuchSolution(e) => Some(e),
+            Self::Rejected(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<NoSuchSolution> for RunError {
+    fn from(e: NoSuchSolution) -> Self {
+        Self::NoSuchSolution(e)
+    }
+}
+
+/// A registered part, as listed by `parts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartInfo {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    /// Whether the default variant is implemented.
+    pub implemented: bool,
+    /// Whether the day parses its input once for both parts.
+    pub has_parse: bool,
+    /// The names of the part's variants, starting with the default one.
+    pub variants: Vec<&'static str>,
+}
+
+/// Every registered part, ordered by year, day and part.
+pub fn parts() -> impl Iterator<Item = PartInfo> {
+    let solutions = solutions();
+    solutions.years().into_iter().flat_map(move |year| {
+        solutions.days(year).flat_map(move |(day, solution)| {
+            solution.parts().map(move |part| PartInfo {
+                year,
+                day,
+                part,
+                implemented: solution.is_implemented(part),
+                has_parse: solution.has_parse(),
+                variants: solution.variants(part).collect(),
+            })
+        })
+    })
+}
+
+/// Finds a part's day and the index of its variant called `variant`, or of the default one for
+/// `None`, checking that it is implemented.
+pub fn lookup(
+    year: i32,
+    day: i32,
+    part: i32,
+    variant: Option<&str>,
+) -> Result<(&'static Day, usize), RunError> {
+    let solution = solutions().part(year, day, part)?;
+    let index = match variant {
+        Some(name) => solution
+            .variant(part, name)
+            .ok_or_else(|| RunError::NoSuchVariant {
+                year,
+                day,
+                part,
+                variant: name.to_owned(),
+                expected: solution.variants(part).collect(),
+            })?,
+        None => 0,
+    };
+    if !solution.is_variant_implemented(part, index) {
+        return Err(RunError::NotImplemented { year, day, part });
+    }
+    Ok((solution, index))
+}
+
+/// Runs the default variant of a part on `input`.
+///
+/// This runs in the calling thread, so panics are caught and returned as errors but hangs are
+/// not guarded against, unlike in the CLI, which runs each part in a child process.
+pub fn run(year: i32, day: i32, part: i32, input: &str) -> Result<Answer, RunError> {
+    run_variant(year, day, part, Variants::<str>::DEFAULT, input)
+}
+
+/// Runs the variant of a part called `variant` on `input`.
+pub fn run_variant(
+    year: i32,
+    day: i32,
+    part: i32,
+    variant: &str,
+    input: &str,
+) -> Result<Answer, RunError> {
+    let (solution, variant) = lookup(year, day, part, Some(variant))?;
+    std::panic::catch_unwind(AssertUnwindSafe(|| {
+        solution.parse(input).solve_variant(part, variant)
+    }))
+    .map_err(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => (*message).to_owned(),
+                Err(_) => "a panic without a message".to_owned(),
+            },
+        };
+        RunError::Panicked(message)
+    })?
+    .map_err(RunError::Rejected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+    #[test]
+    fn runs() {
+        assert_eq!(run(2023, 1, 1, EXAMPLE), Ok(Answer::Integer(142)));
+        assert_eq!(
+            run_variant(2023, 1, 1, "default", EXAMPLE),
+            Ok(Answer::Integer(142))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            run(2023, 25, 2, ""),
+            Err(RunError::NoSuchSolution(NoSuchSolution::Part(2023, 25, 2)))
+        );
+        assert_eq!(
+            run(1999, 1, 1, "").unwrap_err().to_string(),
+            "no solutions for 1999"
+        );
+        assert_eq!(
+            run(2023, 25, 1, ""),
+            Err(RunError::NotImplemented {
+                year: 2023,
+                day: 25,
+                part: 1
+            })
+        );
+        assert_eq!(
+            run_variant(2023, 5, 2, "simd", "").unwrap_err().to_string(),
+            "2023 day 5 part 2 has no variant `simd`, expected one of default, ranges"
+        );
+        assert_eq!(
+            run(2023, 1, 1, "1abc2\n\n").unwrap_err().to_string(),
+            "rejected the input: line 2, column 1: no digit on the line"
+        );
+        assert_eq!(
+            run(2023, 1, 2, "one\nabc").unwrap_err().to_string(),
+            "rejected the input: line 2, column 1: no digit on the line"
+        );
+        assert_eq!(
+            run(2023, 5, 1, ""),
+            Err(RunError::Panicked(
+                "called `Option::unwrap()` on a `None` value".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn registry() {
+        let parts = parts().collect::<Vec<_>>();
+        assert!(parts
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day, pair[0].part)
+                < (pair[1].year, pair[1].day, pair[1].part)));
+
+        let day5 = parts
+            .iter()
+            .find(|info| (info.year, info.day, info.part) == (2023, 5, 2))
+            .unwrap();
+        assert!(day5.implemented && !day5.has_parse);
+        assert_eq!(day5.variants, ["default", "ranges"]);
+        assert!(!parts
+            .iter()
+            .any(|info| (info.year, info.day, info.part) == (2023, 25, 2)));
+    }
+}
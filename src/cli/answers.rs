@@ -0,0 +1,118 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Profile, Result};
+
+/// Accepted answers for a year, stored in the profile's `answers/<year>.toml` as
+///
+/// ```toml
+/// [day01]
+/// part1 = "54573"
+/// part2 = "54591"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+impl DayAnswers {
+    fn part(&mut self, part: i32) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            2 => &mut self.part2,
+            _ => panic!("part {part} does not exist"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(profile: &Profile, year: i32) -> Result<Self> {
+        Self::load_from(profile.answers(year))
+    }
+
+    /// Loads answers from `path`, which is treated as empty if it does not exist yet.
+    pub fn load_from(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let days = match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| format!("could not parse `{}`: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(format!("could not read `{}`: {e}", path.display()).into()),
+        };
+
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, toml::to_string(&self.days)?)
+            .map_err(|e| format!("could not write `{}`: {e}", self.path.display()))?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: i32, part: i32) -> Option<&str> {
+        let day = self.days.get(&Self::key(day))?;
+        match part {
+            1 => day.part1.as_deref(),
+            2 => day.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: i32, part: i32, answer: impl Into<String>) {
+        *self.days.entry(Self::key(day)).or_default().part(part) = Some(answer.into());
+    }
+
+    fn key(day: i32) -> String {
+        format!("day{day:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn round_trip() {
+        let dir = TempDir::new("answers");
+        let path = dir.join("answers/2023.toml");
+
+        let mut answers = Answers::load_from(&path).unwrap();
+        assert_eq!(answers.get(1, 1), None);
+
+        answers.set(1, 1, "54573");
+        answers.set(10, 2, "EHPZPJGL");
+        answers.set(1, 2, "54591");
+        answers.save().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "[day01]\npart1 = \"54573\"\npart2 = \"54591\"\n\n[day10]\npart2 = \"EHPZPJGL\"\n"
+        );
+
+        let loaded = Answers::load_from(&path).unwrap();
+        assert_eq!(loaded, answers);
+        assert_eq!(loaded.get(1, 2), Some("54591"));
+        assert_eq!(loaded.get(10, 1), None);
+    }
+}
@@ -0,0 +1,497 @@
+use std::{ops::RangeInclusive, path::PathBuf, time::Duration};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use super::Job;
+use crate::solutions::YEARS;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc-rs", version, about = "Runs Advent of Code solutions")]
+pub struct Cli {
+    /// The session token to download inputs and submit answers with, instead of reading it from
+    /// `AOC_SESSION`, the user's config directory or `.session`
+    #[arg(long, global = true, value_name = "TOKEN")]
+    pub session: Option<String>,
+    /// Use the inputs, answers and session of this profile from `profiles.toml`
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// The website to download inputs from and submit answers to, instead of `AOC_BASE_URL` or
+    /// https://adventofcode.com
+    #[arg(long, global = true, value_name = "URL")]
+    pub base_url: Option<String>,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run solutions and print their answers
+    Run(RunArgs),
+    /// Run solutions and compare their answers to those recorded in `answers/<year>.toml`
+    Check(RunArgs),
+    /// Benchmark a solution, excluding input loading from the timing
+    Bench(BenchArgs),
+    /// Run a solution and submit its answer, unless earlier verdicts already rule it out
+    Submit(SubmitArgs),
+    /// Download inputs into `inputs/<year>/<day>.txt`, skipping those that are already there
+    Fetch(FetchArgs),
+    /// List the registered years and days
+    List(ListArgs),
+    /// Generate stub solutions for the days of a year, or a single day, that do not exist yet
+    New(NewArgs),
+    /// Run a solution against the example input in `inputs/<year>/<day>.test.txt`
+    Test(TestArgs),
+    /// Parse the input from stdin and run parts of a day on it, reporting the outcomes as JSON,
+    /// which is how the other commands isolate each day in a child process
+    #[command(hide = true)]
+    Worker(WorkerArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct RunArgs {
+    #[arg(value_parser = parse_year)]
+    pub year: i32,
+    /// A day or range of days, such as `5`, `1..=12` or `all`
+    #[arg(value_parser = parse_days, default_value = "all")]
+    pub days: RangeInclusive<i32>,
+    /// Only run this part
+    #[arg(value_parser = clap::value_parser!(i32).range(1..=2))]
+    pub part: Option<i32>,
+    /// Run against the inputs of every profile in `profiles.toml`, reporting each separately
+    #[arg(long)]
+    pub all_profiles: bool,
+    /// Run a single day against this file, or stdin for `-`, instead of the cached input
+    #[arg(long, value_name = "PATH", conflicts_with = "all_profiles")]
+    pub input: Option<PathBuf>,
+    /// Run this variant of the parts instead of the default one, skipping parts without it
+    #[arg(long, value_name = "NAME")]
+    pub variant: Option<String>,
+    #[command(flatten)]
+    pub limits: Limits,
+    /// Also write a JSON record of every part to stdout
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+/// How results are reported. Human-readable text always goes to stderr, and `json` adds one JSON
+/// object per line on stdout for scripts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl RunArgs {
+    pub fn parts(&self) -> RangeInclusive<i32> {
+        match self.part {
+            Some(part) => part..=part,
+            None => 1..=2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Args)]
+pub struct PartArgs {
+    #[arg(value_parser = parse_year)]
+    pub year: i32,
+    #[arg(value_parser = clap::value_parser!(i32).range(1..=25))]
+    pub day: i32,
+    #[arg(value_parser = clap::value_parser!(i32).range(1..=2))]
+    pub part: i32,
+}
+
+/// Limits on running a part, which is done in a child process so that a panic, hang or running
+/// out of memory is reported as a failure of that part.
+#[derive(Debug, Clone, Copy, Args)]
+pub struct Limits {
+    /// Stop parts that take longer than this, such as `30s` or `10m`
+    #[arg(long, value_parser = parse_positive_duration, default_value = "5m")]
+    pub timeout: Duration,
+    /// Stop parts that allocate more than this many MiB
+    #[arg(long, value_name = "MIB", value_parser = clap::value_parser!(u64).range(1..))]
+    pub memory_limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, Args)]
+pub struct SubmitArgs {
+    #[command(flatten)]
+    pub part: PartArgs,
+    /// Wait until the server accepts another answer instead of giving up
+    #[arg(long)]
+    pub wait: bool,
+    #[command(flatten)]
+    pub limits: Limits,
+}
+
+#[derive(Debug, Clone, Copy, Args)]
+pub struct TestArgs {
+    #[command(flatten)]
+    pub part: PartArgs,
+    #[command(flatten)]
+    pub limits: Limits,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct WorkerArgs {
+    #[arg(value_parser = parse_year)]
+    pub year: i32,
+    #[arg(value_parser = clap::value_parser!(i32).range(1..=25))]
+    pub day: i32,
+    /// The variants of parts to run on the parsed input, in order, as `<part>:<variant index>`
+    #[arg(required = true, value_parser = parse_job)]
+    pub jobs: Vec<Job>,
+    #[arg(long, value_name = "MIB")]
+    pub memory_limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub part: PartArgs,
+    /// How long to run the solution for before measuring, such as `2s` or `500ms`
+    #[arg(long, value_parser = parse_duration, default_value = "2s")]
+    pub warmup: Duration,
+    /// How long to measure for
+    #[arg(long, value_parser = parse_positive_duration, default_value = "3s")]
+    pub time: Duration,
+    /// Measure this many runs instead of running for `--time`
+    #[arg(long, short = 'n', conflicts_with = "time", value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: Option<u32>,
+    /// Save the results under this label instead of the current git commit
+    #[arg(long)]
+    pub label: Option<String>,
+    /// Compare the results to those saved under this label or commit
+    #[arg(long, value_name = "BASELINE")]
+    pub compare: Option<String>,
+    /// Benchmark against this file, or stdin for `-`, instead of the cached input
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+    /// Only benchmark this variant of the part, instead of comparing all of them
+    #[arg(long, value_name = "NAME")]
+    pub variant: Option<String>,
+    /// Also write a JSON record with the statistics to stdout
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct FetchArgs {
+    #[arg(value_parser = parse_year)]
+    pub year: i32,
+    /// A day or range of days, such as `5`, `1..=12` or `all`
+    #[arg(value_parser = parse_days, default_value = "all")]
+    pub days: RangeInclusive<i32>,
+    /// Download inputs again even if they are already there
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Copy, Args)]
+pub struct NewArgs {
+    /// Any year of Advent of Code, which does not need to have solutions yet
+    #[arg(value_parser = clap::value_parser!(i32).range(2015..))]
+    pub year: i32,
+    #[arg(value_parser = clap::value_parser!(i32).range(1..=25))]
+    pub day: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, Args)]
+pub struct ListArgs {
+    /// Only list this year
+    #[arg(value_parser = parse_year)]
+    pub year: Option<i32>,
+}
+
+fn parse_year(s: &str) -> Result<i32, String> {
+    let year = s
+        .parse::<i32>()
+        .map_err(|_| format!("`{s}` is not a year"))?;
+
+    if !YEARS.contains(&year) {
+        let years = YEARS
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!("no solutions for {year}, expected one of {years}"));
+    }
+
+    Ok(year)
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<i32>, String> {
+    let parse = |s: &str, last: i32| {
+        s.parse::<i32>()
+            .ok()
+            .filter(|day| (1..=last).contains(day))
+            .ok_or_else(|| format!("`{s}` is not a day between 1 and 25"))
+    };
+
+    let days = if s == "all" {
+        1..=25
+    } else if let Some((start, end)) = s.split_once("..=") {
+        parse(start, 25)?..=parse(end, 25)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        // The end is exclusive, so `1..26` is every day
+        parse(start, 25)?..=parse(end, 26)? - 1
+    } else {
+        let day = parse(s, 25)?;
+        day..=day
+    };
+
+    if days.is_empty() {
+        return Err(format!("`{s}` does not contain any days"));
+    }
+
+    Ok(days)
+}
+
+fn parse_job(s: &str) -> Result<Job, String> {
+    let (part, variant) = s
+        .split_once(':')
+        .ok_or_else(|| format!("`{s}` is not `<part>:<variant>`"))?;
+    Ok(Job {
+        part: part
+            .parse()
+            .ok()
+            .filter(|part| (1..=2).contains(part))
+            .ok_or_else(|| format!("`{part}` is not a part"))?,
+        variant: variant
+            .parse()
+            .map_err(|_| format!("`{variant}` is not a variant index"))?,
+    })
+}
+
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (number, unit) = match s.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(idx) => s.split_at(idx),
+        None => (s, "s"),
+    };
+    let number = number
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite() && *n >= 0.0)
+        .ok_or_else(|| format!("`{s}` is not a duration"))?;
+
+    let secs = match unit {
+        "m" => number * 60.0,
+        "s" => number,
+        "ms" => number / 1e3,
+        "us" | "µs" => number / 1e6,
+        _ => {
+            return Err(format!(
+                "unknown unit `{unit}`, expected `m`, `s`, `ms` or `us`"
+            ))
+        }
+    };
+    Duration::try_from_secs_f64(secs).map_err(|_| format!("`{s}` is too long a duration"))
+}
+
+/// A duration that something has to run within, which would never finish a run if it were 0.
+fn parse_positive_duration(s: &str) -> Result<Duration, String> {
+    let duration = parse_duration(s)?;
+    if duration.is_zero() {
+        return Err(format!("`{s}` is too short, it has to be longer than 0"));
+    }
+    Ok(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("aoc-rs").chain(args.iter().copied()))
+    }
+
+    fn run_args(args: &[&str]) -> RunArgs {
+        let cli = parse(args).unwrap();
+        let Command::Run(args) = cli.command else {
+            panic!("expected run, got {:?}", cli.command);
+        };
+        args
+    }
+
+    #[test]
+    fn run() {
+        let args = run_args(&["run", "2023", "5", "2"]);
+        assert_eq!((args.year, args.days, args.part), (2023, 5..=5, Some(2)));
+    }
+
+    #[test]
+    fn run_ranges() {
+        assert_eq!(run_args(&["run", "2023"]).days, 1..=25);
+        assert_eq!(run_args(&["run", "2023", "all"]).days, 1..=25);
+        assert_eq!(run_args(&["run", "2023", "1..=12"]).days, 1..=12);
+        assert_eq!(run_args(&["run", "2023", "1..12"]).days, 1..=11);
+        assert_eq!(run_args(&["run", "2023", "1..26"]).days, 1..=25);
+        assert!(parse(&["run", "2023", "1..27"]).is_err());
+        assert!(parse(&["run", "2023", "26"]).is_err());
+        assert_eq!(run_args(&["run", "2023", "all"]).parts(), 1..=2);
+        assert!(parse(&["run", "2023", "12..1"]).is_err());
+        assert!(parse(&["run", "2023", "1..=26"]).is_err());
+    }
+
+    #[test]
+    fn bench() {
+        let cli = parse(&["bench", "2023", "5", "2", "--warmup", "500ms", "-n", "100"]).unwrap();
+        let Command::Bench(args) = cli.command else {
+            panic!("expected bench, got {:?}", cli.command);
+        };
+        assert_eq!(args.warmup, Duration::from_millis(500));
+        assert_eq!(args.time, Duration::from_secs(3));
+        assert_eq!(args.iterations, Some(100));
+        assert_eq!((args.label, args.compare), (None, None));
+
+        let cli = parse(&[
+            "bench",
+            "2023",
+            "5",
+            "2",
+            "--label",
+            "new",
+            "--compare",
+            "a1b2c3d",
+        ])
+        .unwrap();
+        let Command::Bench(args) = cli.command else {
+            panic!("expected bench, got {:?}", cli.command);
+        };
+        assert_eq!(args.label.as_deref(), Some("new"));
+        assert_eq!(args.compare.as_deref(), Some("a1b2c3d"));
+
+        assert!(parse(&["bench", "2023", "5", "2", "--time", "1.5"]).is_ok());
+        assert!(parse(&["bench", "2023", "5", "2", "--time", "1h"]).is_err());
+        assert!(parse(&["bench", "2023", "5", "2", "--time", "1e300"]).is_err());
+        assert!(parse(&["bench", "2023", "5", "2", "-n", "0"]).is_err());
+        assert!(parse(&["bench", "2023", "5", "2", "--time", "0"]).is_err());
+        assert!(parse(&["bench", "2023", "5", "2", "--time", "0ms"]).is_err());
+        assert!(parse(&["bench", "2023", "5", "2", "--time", "0.0000000001"]).is_err());
+        assert!(parse(&["run", "2023", "--timeout", "0s"]).is_err());
+        assert!(parse(&["bench", "2023", "5", "2", "--warmup", "0"]).is_ok());
+        assert!(parse(&["bench", "2023", "5", "2", "-n", "5", "--time", "1s"]).is_err());
+    }
+
+    #[test]
+    fn input() {
+        let args = run_args(&["run", "2023", "1", "--input", "-"]);
+        assert_eq!(args.input, Some(PathBuf::from("-")));
+        assert!(parse(&["run", "2023", "--input", "a.txt", "--all-profiles"]).is_err());
+        assert!(parse(&["bench", "2023", "1", "1", "--input", "a.txt"]).is_ok());
+        // Answers to inputs other than the official one must never be submitted
+        assert!(parse(&["submit", "2023", "1", "1", "--input", "a.txt"]).is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(run_args(&["run", "2023"]).format, Format::Text);
+        assert_eq!(
+            run_args(&["run", "2023", "--format", "json"]).format,
+            Format::Json
+        );
+        assert!(parse(&["run", "2023", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn variant() {
+        assert_eq!(run_args(&["run", "2023", "5"]).variant, None);
+        let args = run_args(&["run", "2023", "5", "2", "--variant", "ranges"]);
+        assert_eq!(args.variant.as_deref(), Some("ranges"));
+
+        let cli = parse(&["bench", "2023", "5", "2", "--variant", "default"]).unwrap();
+        let Command::Bench(args) = cli.command else {
+            panic!("expected bench, got {:?}", cli.command);
+        };
+        assert_eq!(args.variant.as_deref(), Some("default"));
+    }
+
+    #[test]
+    fn limits() {
+        let args = run_args(&["run", "2023"]);
+        assert_eq!(args.limits.timeout, Duration::from_secs(300));
+        assert_eq!(args.limits.memory_limit, None);
+
+        let args = run_args(&["run", "2023", "--timeout", "1.5m", "--memory-limit", "512"]);
+        assert_eq!(args.limits.timeout, Duration::from_secs(90));
+        assert_eq!(args.limits.memory_limit, Some(512));
+        assert!(parse(&["submit", "2023", "1", "1", "--timeout", "10s"]).is_ok());
+        assert!(parse(&["test", "2023", "1", "1", "--memory-limit", "0"]).is_err());
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("5µs"), Ok(Duration::from_micros(5)));
+        assert_eq!(parse_duration("5us"), Ok(Duration::from_micros(5)));
+        assert_eq!(parse_duration("2.5ms"), Ok(Duration::from_micros(2500)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert_eq!(
+            parse_duration(&format!("{}m", "9".repeat(300))),
+            Err(format!("`{}m` is too long a duration", "9".repeat(300)))
+        );
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("s").is_err());
+    }
+
+    #[test]
+    fn worker() {
+        let cli = parse(&["worker", "2023", "5", "1:0", "2:1", "--memory-limit", "64"]).unwrap();
+        let Command::Worker(args) = cli.command else {
+            panic!("expected worker, got {:?}", cli.command);
+        };
+        assert_eq!(
+            args.jobs,
+            [
+                Job {
+                    part: 1,
+                    variant: 0
+                },
+                Job {
+                    part: 2,
+                    variant: 1
+                }
+            ]
+        );
+        assert_eq!(args.memory_limit, Some(64));
+        assert!(parse(&["worker", "2023", "5"]).is_err());
+        assert!(parse(&["worker", "2023", "5", "3:0"]).is_err());
+    }
+
+    #[test]
+    fn new() {
+        let cli = parse(&["new", "2015"]).unwrap();
+        let Command::New(args) = cli.command else {
+            panic!("expected new, got {:?}", cli.command);
+        };
+        assert_eq!((args.year, args.day), (2015, None));
+        assert!(parse(&["new", "2024", "25"]).is_ok());
+        assert!(parse(&["new", "2014"]).is_err());
+        assert!(parse(&["new", "2024", "26"]).is_err());
+    }
+
+    #[test]
+    fn fetch() {
+        let cli = parse(&["fetch", "2023", "1..=12", "--force"]).unwrap();
+        let Command::Fetch(args) = cli.command else {
+            panic!("expected fetch, got {:?}", cli.command);
+        };
+        assert_eq!((args.year, args.days, args.force), (2023, 1..=12, true));
+    }
+
+    #[test]
+    fn rejects_out_of_range() {
+        assert!(parse(&["run", "2023", "0", "1"]).is_err());
+        assert!(parse(&["run", "2023", "26", "1"]).is_err());
+        assert!(parse(&["run", "2023", "1", "3"]).is_err());
+        assert!(parse(&["bench", "2023", "1"]).is_err());
+        assert!(parse(&["fetch", "2023", "x"]).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_year() {
+        let err = parse(&["run", "1999", "1", "1"]).unwrap_err();
+        assert!(err.to_string().contains("no solutions for 1999"));
+    }
+}
@@ -0,0 +1,174 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use super::{bench::Stats, Result};
+
+/// Benchmark results stored in `bench/baselines.json`, grouped by the label they were saved under.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Baselines {
+    path: PathBuf,
+    labels: BTreeMap<String, BTreeMap<String, Stats>>,
+}
+
+impl Baselines {
+    pub fn load() -> Result<Self> {
+        Self::load_from("bench/baselines.json")
+    }
+
+    /// Loads baselines from `path`, which is treated as empty if it does not exist yet.
+    pub fn load_from(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let labels = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| format!("could not parse `{}`: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(format!("could not read `{}`: {e}", path.display()).into()),
+        };
+
+        Ok(Self { path, labels })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.labels)?)
+            .map_err(|e| format!("could not write `{}`: {e}", self.path.display()))?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The results saved under `label` for a part's `variant`, or its default one for `None`.
+    pub fn get(
+        &self,
+        label: &str,
+        year: i32,
+        day: i32,
+        part: i32,
+        variant: Option<&str>,
+    ) -> Option<&Stats> {
+        self.labels
+            .get(label)?
+            .get(&Self::key(year, day, part, variant))
+    }
+
+    pub fn insert(
+        &mut self,
+        label: impl Into<String>,
+        year: i32,
+        day: i32,
+        part: i32,
+        variant: Option<&str>,
+        stats: Stats,
+    ) {
+        self.labels
+            .entry(label.into())
+            .or_default()
+            .insert(Self::key(year, day, part, variant), stats);
+    }
+
+    /// Default variants keep the keys from before parts had variants, so that older baselines
+    /// can still be compared to.
+    fn key(year: i32, day: i32, part: i32, variant: Option<&str>) -> String {
+        match variant {
+            Some(variant) => format!("{year}-{day:02}-{part}-{variant}"),
+            None => format!("{year}-{day:02}-{part}"),
+        }
+    }
+}
+
+/// The short hash of the commit checked out in `dir`, or `None` outside of a git repository.
+/// Uncommitted changes add `-dirty-` and the short hash of the working tree, so that benchmarks of
+/// different changes on the same commit do not overwrite each other. Untracked files are not part
+/// of that tree, so a new day that has not been added to git yet does not make the label dirty.
+pub fn git_label(dir: &Path) -> Option<String> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    // Records the tracked files of the working tree as a commit object without touching it or the
+    // stash, and prints nothing if there is nothing to stash
+    let stash = git(&["stash", "create"])?;
+    if stash.is_empty() {
+        return Some(commit);
+    }
+    let tree = git(&["rev-parse", "--short", &format!("{stash}^{{tree}}")])?;
+    Some(format!("{commit}-dirty-{tree}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn round_trip() {
+        let dir = TempDir::new("baselines");
+        let path = dir.join("bench/baselines.json");
+
+        let mut baselines = Baselines::load_from(&path).unwrap();
+        assert_eq!(baselines.get("before", 2023, 5, 2, None), None);
+
+        let stats = Stats::from_samples(vec![Duration::from_micros(10), Duration::from_micros(12)]);
+        baselines.insert("before", 2023, 5, 2, None, stats);
+        baselines.save().unwrap();
+
+        let loaded = Baselines::load_from(&path).unwrap();
+        assert_eq!(loaded, baselines);
+        assert_eq!(loaded.get("before", 2023, 5, 2, None), Some(&stats));
+        assert_eq!(loaded.get("before", 2023, 5, 1, None), None);
+        assert_eq!(loaded.get("before", 2023, 5, 2, Some("ranges")), None);
+        assert_eq!(loaded.get("after", 2023, 5, 2, None), None);
+    }
+
+    #[test]
+    fn git_labels() {
+        let dir = TempDir::new("git-label");
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .current_dir(dir.path())
+                .args(["-c", "user.name=aoc", "-c", "user.email=aoc@localhost"])
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?}");
+        };
+        assert_eq!(git_label(dir.path()), None);
+
+        git(&["init", "-q"]);
+        std::fs::write(dir.join("day01.rs"), "1").unwrap();
+        git(&["add", "day01.rs"]);
+        git(&["commit", "-qm", "day 1"]);
+        let clean = git_label(dir.path()).unwrap();
+        assert!(!clean.contains("dirty"));
+        std::fs::write(dir.join("day02.rs"), "1").unwrap();
+        assert_eq!(git_label(dir.path()).unwrap(), clean);
+
+        std::fs::write(dir.join("day01.rs"), "2").unwrap();
+        let first = git_label(dir.path()).unwrap();
+        assert!(first.starts_with(&format!("{clean}-dirty-")));
+        assert_eq!(git_label(dir.path()).unwrap(), first);
+        std::fs::write(dir.join("day01.rs"), "3").unwrap();
+        let second = git_label(dir.path()).unwrap();
+        assert_ne!(second, first);
+        assert!(second.starts_with(&format!("{clean}-dirty-")));
+    }
+}
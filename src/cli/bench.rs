@@ -0,0 +1,311 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// How long to benchmark for, after warming up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    Time(Duration),
+    Iterations(u32),
+}
+
+/// Runs `f` for `warmup`, then times individual runs of it until `measure` is satisfied.
+pub fn bench(f: impl Fn(), warmup: Duration, measure: Measure) -> Stats {
+    let warmup_start = Instant::now();
+    while warmup_start.elapsed() < warmup {
+        f();
+    }
+
+    let mut samples = Vec::new();
+    let true_start = Instant::now();
+    loop {
+        match measure {
+            Measure::Time(time) if true_start.elapsed() >= time => break,
+            Measure::Iterations(n) if samples.len() >= n as usize => break,
+            _ => {}
+        }
+
+        let start = Instant::now();
+        f();
+        samples.push(start.elapsed());
+    }
+
+    Stats::from_samples(samples)
+}
+
+/// Counts of samples outside of Tukey's fences, where mild outliers are more than 1.5 times the
+/// interquartile range away from the nearest quartile and severe outliers more than 3 times.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Outliers {
+    pub low_severe: usize,
+    pub low_mild: usize,
+    pub high_mild: usize,
+    pub high_severe: usize,
+}
+
+impl Outliers {
+    pub fn total(&self) -> usize {
+        self.low_severe + self.low_mild + self.high_mild + self.high_severe
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "std_dev_ns", with = "nanos")]
+    pub std_dev: Duration,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+    pub outliers: Outliers,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples were taken");
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let iqr = q3 - q1;
+        let mut outliers = Outliers::default();
+        for &n in &nanos {
+            if n < q1 - 3.0 * iqr {
+                outliers.low_severe += 1;
+            } else if n < q1 - 1.5 * iqr {
+                outliers.low_mild += 1;
+            } else if n > q3 + 3.0 * iqr {
+                outliers.high_severe += 1;
+            } else if n > q3 + 1.5 * iqr {
+                outliers.high_mild += 1;
+            }
+        }
+
+        let duration = |nanos: f64| Duration::from_nanos(nanos.round() as u64);
+        Self {
+            runs,
+            mean: duration(mean),
+            std_dev: duration(variance.sqrt()),
+            min: samples[0],
+            median: duration(percentile(&nanos, 50.0)),
+            p95: duration(percentile(&nanos, 95.0)),
+            max: samples[runs - 1],
+            outliers,
+        }
+    }
+}
+
+/// Linearly interpolates the `p`th percentile of the sorted `samples`.
+fn percentile(samples: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (samples.len() - 1) as f64;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    samples[low] + (samples[high] - samples[low]) * (rank - low as f64)
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Runs:     {}", self.runs)?;
+        writeln!(f, "Mean:     {:.2?} ± {:.2?}", self.mean, self.std_dev)?;
+        writeln!(f, "Min:      {:.2?}", self.min)?;
+        writeln!(f, "Median:   {:.2?}", self.median)?;
+        writeln!(f, "p95:      {:.2?}", self.p95)?;
+        writeln!(f, "Max:      {:.2?}", self.max)?;
+        let outliers = &self.outliers;
+        writeln!(
+            f,
+            "Outliers: {} ({} low severe, {} low mild, {} high mild, {} high severe)",
+            outliers.total(),
+            outliers.low_severe,
+            outliers.low_mild,
+            outliers.high_mild,
+            outliers.high_severe
+        )
+    }
+}
+
+/// The relative change in mean time between two benchmarks, and how likely it is to be noise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    /// `current / baseline - 1`, so negative when the current run is faster.
+    pub change: f64,
+    /// Two-sided p-value of Welch's t-test, approximated with the normal distribution since
+    /// benchmarks usually take thousands of samples.
+    pub p_value: f64,
+}
+
+impl Comparison {
+    pub const SIGNIFICANCE: f64 = 0.05;
+
+    pub fn new(baseline: &Stats, current: &Stats) -> Self {
+        let mean = |stats: &Stats| stats.mean.as_nanos() as f64;
+        let variance =
+            |stats: &Stats| (stats.std_dev.as_nanos() as f64).powi(2) / stats.runs as f64;
+
+        let change = mean(current) / mean(baseline) - 1.0;
+        let standard_error = (variance(baseline) + variance(current)).sqrt();
+        let p_value = if standard_error == 0.0 {
+            if mean(current) == mean(baseline) {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            let t = (mean(current) - mean(baseline)) / standard_error;
+            2.0 * (1.0 - normal_cdf(t.abs()))
+        };
+
+        Self { change, p_value }
+    }
+
+    pub fn is_significant(&self) -> bool {
+        self.p_value < Self::SIGNIFICANCE
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verdict = if !self.is_significant() {
+            "no significant change".to_owned()
+        } else if self.change < 0.0 {
+            format!("{:.2}x faster", 1.0 / (1.0 + self.change))
+        } else {
+            format!("{:.2}x slower", 1.0 + self.change)
+        };
+        write!(
+            f,
+            "{:+.2}% (p = {:.3}), {verdict}",
+            self.change * 100.0,
+            self.p_value
+        )
+    }
+}
+
+/// Abramowitz and Stegun's approximation 7.1.26 of the error function, which is accurate to
+/// within 1.5e-7.
+fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * z);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-z * z).exp();
+    if x >= 0.0 {
+        (1.0 + erf) / 2.0
+    } else {
+        (1.0 - erf) / 2.0
+    }
+}
+
+/// (De)serializes durations as a number of nanoseconds.
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_samples() {
+        let mut samples = (1..=100).map(Duration::from_micros).collect::<Vec<_>>();
+        samples.push(Duration::from_millis(1));
+        samples.reverse();
+
+        let stats = Stats::from_samples(samples);
+        assert_eq!(stats.runs, 101);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(51));
+        assert_eq!(stats.p95, Duration::from_micros(96));
+        assert_eq!(stats.max, Duration::from_millis(1));
+        assert_eq!(
+            stats.outliers,
+            Outliers {
+                high_severe: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    fn stats(mean_us: u64, std_dev_us: u64, runs: usize) -> Stats {
+        Stats {
+            runs,
+            mean: Duration::from_micros(mean_us),
+            std_dev: Duration::from_micros(std_dev_us),
+            min: Duration::ZERO,
+            median: Duration::ZERO,
+            p95: Duration::ZERO,
+            max: Duration::ZERO,
+            outliers: Outliers::default(),
+        }
+    }
+
+    #[test]
+    fn comparison() {
+        let baseline = stats(200, 20, 1000);
+
+        let faster = Comparison::new(&baseline, &stats(100, 10, 1000));
+        assert_eq!(faster.change, -0.5);
+        assert!(faster.is_significant());
+        assert!(faster.to_string().ends_with("2.00x faster"));
+
+        let noisy = Comparison::new(&baseline, &stats(201, 50, 10));
+        assert!(!noisy.is_significant());
+        assert!(noisy.to_string().ends_with("no significant change"));
+
+        assert!((normal_cdf(1.959964) - 0.975).abs() < 1e-6);
+    }
+
+    #[test]
+    fn serialized_as_nanos() {
+        let json = serde_json::to_string(&stats(1, 2, 3)).unwrap();
+        assert!(json.starts_with(r#"{"runs":3,"mean_ns":1000,"std_dev_ns":2000,"#));
+        assert_eq!(
+            serde_json::from_str::<Stats>(&json).unwrap(),
+            stats(1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn iterations() {
+        let runs = std::cell::Cell::new(0);
+        let stats = bench(
+            || runs.set(runs.get() + 1),
+            Duration::ZERO,
+            Measure::Iterations(10),
+        );
+        assert_eq!(stats.runs, 10);
+        assert_eq!(runs.get(), 10);
+    }
+}
@@ -0,0 +1,82 @@
+use std::{error::Error, time::Duration};
+
+use super::{
+    verdicts::{self, Verdict},
+    Result,
+};
+
+/// Talks to the Advent of Code website, or to a stand-in for it at another base URL.
+///
+/// Requests are authenticated with the token passed to them, as it depends on the profile.
+pub struct Client {
+    base_url: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!("aoc-rs/", env!("CARGO_PKG_VERSION")))
+            .build();
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            agent,
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Downloads a day's input exactly as the server sent it.
+    pub fn input(&self, token: &str, year: i32, day: i32) -> Result<String> {
+        let response = self
+            .agent
+            .get(&format!("{}/{year}/day/{day}/input", self.base_url))
+            .set("Cookie", &format!("session={token}"))
+            .call()
+            .map_err(request_error)?;
+        Ok(response.into_string()?)
+    }
+
+    /// Posts an answer, returning the verdict and how many seconds to wait before the next one.
+    pub fn submit(
+        &self,
+        token: &str,
+        year: i32,
+        day: i32,
+        part: i32,
+        answer: &str,
+    ) -> Result<(Verdict, u64)> {
+        let response = self
+            .agent
+            .post(&format!("{}/{year}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={token}"))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(request_error)?;
+        let body = response.into_string()?;
+        verdicts::parse_response(&body)
+            .ok_or_else(|| "could not understand the server's response".into())
+    }
+}
+
+/// Includes the server's explanation in errors, as it sends plain text messages such as
+/// "Please don't repeatedly request this endpoint before it unlocks!" with 4xx responses.
+fn request_error(e: ureq::Error) -> Box<dyn Error> {
+    match e {
+        ureq::Error::Status(code, response) => {
+            let url = response.get_url().to_owned();
+            let body = response.into_string().unwrap_or_default();
+            let message = body.lines().next().unwrap_or_default().trim();
+            if message.is_empty() || message.starts_with('<') {
+                format!("{url} returned {code}").into()
+            } else {
+                format!("{url} returned {code}: {message}").into()
+            }
+        }
+        e => e.to_string().into(),
+    }
+}
@@ -0,0 +1,137 @@
+use std::{io::Read, path::Path};
+
+use super::{Client, Profile, Result};
+
+/// The state of a day's input in a profile's `inputs/<year>/<day>.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cached {
+    Valid(String),
+    Missing,
+    /// The file exists but is not a puzzle input, for the given reason.
+    Invalid(&'static str),
+}
+
+pub fn cached(profile: &Profile, year: i32, day: i32) -> Result<Cached> {
+    let path = profile.input(year, day);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Cached::Missing),
+        Err(e) => return Err(format!("could not read `{}`: {e}", path.display()).into()),
+    };
+
+    Ok(match validate(&contents) {
+        Ok(()) => Cached::Valid(contents),
+        Err(reason) => Cached::Invalid(reason),
+    })
+}
+
+/// Checks that `contents` look like a puzzle input rather than a failed download.
+pub fn validate(contents: &str) -> std::result::Result<(), &'static str> {
+    let start = contents.trim_start().to_ascii_lowercase();
+    if contents.trim().is_empty() {
+        Err("is empty")
+    } else if start.starts_with("<!doctype") || start.starts_with("<html") {
+        Err("is an HTML page")
+    } else if start.starts_with("please don't repeatedly request")
+        || start.starts_with("puzzle inputs differ by user")
+    {
+        Err("is an error message from the server")
+    } else {
+        Ok(())
+    }
+}
+
+/// Loads a day's input from the cache, only reading the session token to download it if it is
+/// missing or invalid.
+///
+/// The trailing newline is removed, as the solutions were written against inputs without it.
+pub fn load(client: &Client, profile: &Profile, year: i32, day: i32) -> Result<String> {
+    let path = profile.input(year, day);
+    let mut input = match cached(profile, year, day)? {
+        Cached::Valid(input) => input,
+        Cached::Missing => {
+            let token = profile
+                .token()
+                .map_err(|e| format!("`{}` is not downloaded yet, and {e}", path.display()))?;
+            download(client, profile, &token, year, day)?
+        }
+        Cached::Invalid(reason) => {
+            let token = profile.token().map_err(|e| {
+                format!(
+                    "`{}` {reason} and cannot be replaced, as {e}",
+                    path.display()
+                )
+            })?;
+            eprintln!(
+                "warning: `{}` {reason}, downloading it again",
+                path.display()
+            );
+            download(client, profile, &token, year, day)?
+        }
+    };
+
+    input.truncate(input.trim_end_matches('\n').len());
+    Ok(input)
+}
+
+/// Reads an input given instead of the cached one, from stdin if `path` is `-`. Neither the
+/// cache nor the session are touched, so that hand-made inputs work offline.
+pub fn read(path: &Path) -> Result<String> {
+    let mut input = if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("could not read the input from stdin: {e}"))?;
+        input
+    } else {
+        std::fs::read_to_string(path)
+            .map_err(|e| format!("could not read `{}`: {e}", path.display()))?
+    };
+
+    input.truncate(input.trim_end_matches('\n').len());
+    Ok(input)
+}
+
+/// Downloads a day's input into the cache, replacing whatever was there.
+pub fn download(
+    client: &Client,
+    profile: &Profile,
+    token: &str,
+    year: i32,
+    day: i32,
+) -> Result<String> {
+    let input = client
+        .input(token, year, day)
+        .map_err(|e| format!("could not download input for {year} day {day}: {e}"))?;
+    validate(&input).map_err(|reason| format!("downloaded input for {year} day {day} {reason}"))?;
+
+    let path = profile.input(year, day);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, &input)
+        .map_err(|e| format!("could not write `{}`: {e}", path.display()))?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        assert_eq!(validate("1abc2\npqr3stu8vwx\n"), Ok(()));
+        assert_eq!(validate(""), Err("is empty"));
+        assert_eq!(validate("\n\n"), Err("is empty"));
+        // Inputs cached by older versions had their trailing newline removed
+        assert_eq!(validate("1abc2\npqr3stu8vwx"), Ok(()));
+        assert_eq!(
+            validate("<!DOCTYPE html>\n<html lang=\"en-us\">\n"),
+            Err("is an HTML page")
+        );
+        assert_eq!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err("is an error message from the server")
+        );
+    }
+}
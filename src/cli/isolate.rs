@@ -0,0 +1,423 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    io::{BufRead, BufReader, Read, Write},
+    panic::AssertUnwindSafe,
+    process::{ExitCode, Stdio},
+    sync::{
+        atomic::{AtomicBool, AtomicIsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Limits, Result, WorkerArgs};
+use crate::solutions::{solutions, Answer, SolveError};
+
+/// A variant of a part for a worker to run, given on its command line as `<part>:<variant>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Job {
+    pub part: i32,
+    /// The index of the variant, as returned by `Day::variant`.
+    pub variant: usize,
+}
+
+impl Display for Job {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.part, self.variant)
+    }
+}
+
+/// An answer calculated by a worker, with how long it took to solve the parsed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: Answer,
+    pub solve_time: Duration,
+}
+
+/// The outcomes of a day's jobs, in the order they were given, with how long it took to parse
+/// the input that they shared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayRun {
+    pub parse_time: Duration,
+    pub runs: Vec<std::result::Result<Run, Failure>>,
+}
+
+/// Why a part stopped without an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The part found the input malformed.
+    Rejected(SolveError),
+    /// The panic message, including where it happened.
+    Panicked(String),
+    TimedOut(Duration),
+    /// The memory limit in MiB, which the part tried to allocate past.
+    OutOfMemory(u64),
+    /// The worker exited some other way, such as being killed by a signal, with what it printed.
+    Crashed(String),
+}
+
+impl Failure {
+    /// A few words on what happened, to fit in a table.
+    pub fn short(&self) -> String {
+        match self {
+            Failure::Rejected(_) => "rejected input".to_owned(),
+            Failure::Panicked(_) => "panicked".to_owned(),
+            Failure::TimedOut(timeout) => format!("timed out after {timeout:.2?}"),
+            Failure::OutOfMemory(limit) => format!("exceeded {limit} MiB"),
+            Failure::Crashed(_) => "crashed".to_owned(),
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Rejected(e) => write!(f, "rejected the input: {e}"),
+            Failure::Panicked(message) => write!(f, "{message}"),
+            Failure::TimedOut(timeout) => write!(f, "did not finish within {timeout:.2?}"),
+            Failure::OutOfMemory(limit) => write!(f, "tried to allocate more than {limit} MiB"),
+            Failure::Crashed(output) => write!(f, "crashed: {output}"),
+        }
+    }
+}
+
+/// Starts each report on a worker's stdout, so that they can be told apart from anything the
+/// parts print there, even without a newline at the end.
+const REPORT_START: u8 = 0x1e;
+
+/// What a worker writes to stdout, as a line of JSON after parsing and after each job.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Report {
+    Parsed { parse_ns: u64 },
+    Answer { answer: Answer, solve_ns: u64 },
+    Rejected { error: SolveError },
+    Panic { message: String },
+}
+
+const MIB: usize = 1 << 20;
+
+/// Runs jobs on a day's input in a child process, which parses it once for all of them, so that a
+/// panic, hang or runaway allocation only fails the job it happened in. `--timeout` applies to
+/// parsing and to each job separately. `Err` is only returned if the child could not be run at all.
+pub fn run(year: i32, day: i32, jobs: &[Job], limits: Limits, input: &str) -> Result<DayRun> {
+    let mut day_run = DayRun {
+        parse_time: Duration::ZERO,
+        runs: Vec::new(),
+    };
+    let mut parsed_once = false;
+    // A worker that has to be stopped takes the job it was running with it, so the remaining jobs
+    // are run by a new one
+    while day_run.runs.len() < jobs.len() {
+        let remaining = &jobs[day_run.runs.len()..];
+        let mut command = std::process::Command::new(std::env::current_exe()?);
+        command
+            .arg("worker")
+            .args([year, day].map(|arg| arg.to_string()))
+            .args(remaining.iter().map(Job::to_string));
+        if let Some(limit) = limits.memory_limit {
+            command.args(["--memory-limit", &limit.to_string()]);
+        }
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("could not start a worker: {e}"))?;
+
+        // The pipes are serviced on their own threads, so that a large input or output cannot
+        // block the child while it is being timed, and reports arrive as soon as they are written
+        let mut stdin = child.stdin.take().unwrap();
+        let owned_input = input.to_owned();
+        std::thread::spawn(move || stdin.write_all(owned_input.as_bytes()));
+        let (reports, received) = mpsc::channel();
+        let mut stdout = BufReader::new(child.stdout.take().unwrap());
+        std::thread::spawn(move || {
+            let mut line = Vec::new();
+            while matches!(stdout.read_until(b'\n', &mut line), Ok(1..)) {
+                // What the parts print is passed on, on stderr as stdout may be JSON records
+                let (output, report) = split_report(&line);
+                let _ = std::io::stderr().write_all(output);
+                if let Some(report) = report {
+                    let _ = reports.send(report);
+                }
+                line.clear();
+            }
+        });
+        let mut stderr = child.stderr.take().unwrap();
+        let stderr = std::thread::spawn(move || {
+            let mut output = String::new();
+            let _ = stderr.read_to_string(&mut output);
+            output
+        });
+
+        let mut parsed = false;
+        let mut deadline = Instant::now() + limits.timeout;
+        let failure = loop {
+            let report =
+                match received.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(report) => report,
+                    Err(RecvTimeoutError::Timeout) => {
+                        child.kill()?;
+                        child.wait()?;
+                        break Some(Failure::TimedOut(limits.timeout));
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        let status = child.wait()?;
+                        let stderr = stderr.join().unwrap();
+                        if day_run.runs.len() == jobs.len() {
+                            break None;
+                        }
+                        // Failed allocations abort after printing this, rather than unwinding
+                        if let (true, Some(limit)) =
+                            (stderr.contains("memory allocation of"), limits.memory_limit)
+                        {
+                            break Some(Failure::OutOfMemory(limit));
+                        }
+                        let output = stderr.trim();
+                        break Some(Failure::Crashed(if output.is_empty() {
+                            status.to_string()
+                        } else {
+                            format!("{status}, {output}")
+                        }));
+                    }
+                };
+            deadline = Instant::now() + limits.timeout;
+            let outcome = match report {
+                Report::Parsed { parse_ns } => {
+                    if !parsed_once {
+                        day_run.parse_time = Duration::from_nanos(parse_ns);
+                    }
+                    (parsed, parsed_once) = (true, true);
+                    continue;
+                }
+                Report::Answer { answer, solve_ns } => Ok(Run {
+                    answer,
+                    solve_time: Duration::from_nanos(solve_ns),
+                }),
+                Report::Rejected { error } => Err(Failure::Rejected(error)),
+                Report::Panic { message } => Err(Failure::Panicked(message)),
+            };
+            day_run.runs.push(outcome);
+        };
+
+        if let Some(failure) = failure {
+            // Stopping before the input was parsed fails every job that needed it
+            let failed = if parsed { 1 } else { remaining.len() };
+            day_run.runs.extend(vec![Err(failure); failed]);
+        }
+    }
+    Ok(day_run)
+}
+
+/// Splits a line of a worker's stdout into what a part printed and the report after it, if any.
+fn split_report(line: &[u8]) -> (&[u8], Option<Report>) {
+    if let Some(start) = line.iter().rposition(|&b| b == REPORT_START) {
+        if let Ok(report) = serde_json::from_slice(&line[start + 1..]) {
+            return (&line[..start], Some(report));
+        }
+    }
+    (line, None)
+}
+
+/// The message and location of the first panic, as the payload only has the message.
+static PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+/// Parses the input from stdin once and runs each job on it, reporting the outcomes to the parent
+/// on stdout as they happen.
+pub fn worker(args: WorkerArgs) -> Result<ExitCode> {
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input)?;
+
+    let solution = solutions().day(args.year, args.day)?;
+    std::panic::set_hook(Box::new(|info| {
+        PANIC
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| info.to_string());
+    }));
+    let report = |report: Report| -> Result<()> {
+        println!(
+            "{}{}",
+            REPORT_START as char,
+            serde_json::to_string(&report)?
+        );
+        Ok(())
+    };
+    let panic_message = || {
+        PANIC
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| "panicked".to_owned())
+    };
+    // Only what parsing or a job allocates counts towards the limit, not the input it was given
+    // or what was parsed before it
+    let limit = || {
+        if let Some(limit) = args.memory_limit {
+            COUNTING.store(true, Ordering::Relaxed);
+            LIMIT.store(
+                ALLOCATED.load(Ordering::Relaxed) + (limit as usize * MIB) as isize,
+                Ordering::Relaxed,
+            );
+        }
+    };
+
+    limit();
+    let start = Instant::now();
+    let parsed = match std::panic::catch_unwind(AssertUnwindSafe(|| solution.parse(&input))) {
+        Ok(parsed) => parsed,
+        Err(_) => {
+            // None of the jobs can run without the parsed input
+            let message = panic_message();
+            for _ in &args.jobs {
+                report(Report::Panic {
+                    message: message.clone(),
+                })?;
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+    };
+    report(Report::Parsed {
+        parse_ns: start.elapsed().as_nanos() as u64,
+    })?;
+
+    for job in &args.jobs {
+        limit();
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            let start = Instant::now();
+            let answer = parsed.solve_variant(job.part, job.variant);
+            (answer, start.elapsed())
+        }));
+        LIMIT.store(isize::MAX, Ordering::Relaxed);
+        report(match result {
+            Ok((Ok(answer), solve_time)) => Report::Answer {
+                answer,
+                solve_ns: solve_time.as_nanos() as u64,
+            },
+            Ok((Err(error), _)) => Report::Rejected { error },
+            Err(_) => Report::Panic {
+                message: panic_message(),
+            },
+        })?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Whether allocations are counted, which is only the case in workers given a memory limit, so
+/// that every other process, such as one running `bench`, only pays for loading this.
+static COUNTING: AtomicBool = AtomicBool::new(false);
+/// The bytes allocated since counting started, which is negative after freeing more than that.
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+static LIMIT: AtomicIsize = AtomicIsize::new(isize::MAX);
+
+/// The system allocator, except that allocations fail once a worker's memory limit is reached.
+/// It only takes effect in binaries that register it as their `#[global_allocator]`.
+pub struct LimitedAlloc;
+
+impl LimitedAlloc {
+    /// Counts `size` more bytes as allocated, unless that would exceed the limit.
+    #[inline]
+    fn reserve(size: usize) -> bool {
+        if !COUNTING.load(Ordering::Relaxed) {
+            return true;
+        }
+        let size = size as isize;
+        let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        if allocated.saturating_add(size) > LIMIT.load(Ordering::Relaxed) {
+            ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+            return false;
+        }
+        true
+    }
+
+    /// Stops counting `size` bytes that were freed or could not be allocated after all.
+    #[inline]
+    fn release(size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            ALLOCATED.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for LimitedAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !Self::reserve(layout.size()) {
+            return std::ptr::null_mut();
+        }
+        let ptr = System.alloc(layout);
+        if ptr.is_null() {
+            Self::release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if !Self::reserve(layout.size()) {
+            return std::ptr::null_mut();
+        }
+        let ptr = System.alloc_zeroed(layout);
+        if ptr.is_null() {
+            Self::release(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::release(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let grown = new_size.saturating_sub(layout.size());
+        if !Self::reserve(grown) {
+            return std::ptr::null_mut();
+        }
+        let new = System.realloc(ptr, layout, new_size);
+        if new.is_null() {
+            Self::release(grown);
+        } else if new_size < layout.size() {
+            Self::release(layout.size() - new_size);
+        }
+        new
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports() {
+        let report = |line: &str| {
+            let (output, report) = split_report(line.as_bytes());
+            (
+                std::str::from_utf8(output).unwrap().to_owned(),
+                report.map(|report| serde_json::to_string(&report).unwrap()),
+            )
+        };
+        let parsed = r#"{"parsed":{"parse_ns":5}}"#;
+
+        assert_eq!(
+            report(&format!("\x1e{parsed}\n")),
+            (String::new(), Some(parsed.to_owned()))
+        );
+        // Output without a newline ends up on the same line as the report
+        assert_eq!(
+            report(&format!("1 2 3\x1e{parsed}\n")),
+            ("1 2 3".to_owned(), Some(parsed.to_owned()))
+        );
+        assert_eq!(
+            report(&format!("{parsed}\n")),
+            (format!("{parsed}\n"), None)
+        );
+        assert_eq!(
+            report("\x1enot json\n"),
+            ("\x1enot json\n".to_owned(), None)
+        );
+    }
+}
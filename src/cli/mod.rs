@@ -0,0 +1,767 @@
+mod answers;
+mod args;
+mod baselines;
+mod bench;
+mod client;
+mod inputs;
+mod isolate;
+mod profiles;
+mod report;
+mod scaffold;
+mod session;
+mod verdicts;
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    hint::black_box,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use clap::Parser;
+
+pub use answers::Answers;
+pub use args::{
+    BenchArgs, Cli, Command, FetchArgs, Format, Limits, ListArgs, NewArgs, PartArgs, RunArgs,
+    SubmitArgs, TestArgs, WorkerArgs,
+};
+pub use baselines::Baselines;
+pub use bench::{Comparison, Measure, Outliers, Stats};
+pub use client::Client;
+pub use inputs::Cached;
+pub use isolate::{DayRun, Failure, Job, LimitedAlloc, Run};
+pub use profiles::Profile;
+pub use report::{Outcome, Record, Row, Status};
+pub use session::Session;
+pub use verdicts::{Submission, Submissions, Verdict};
+
+use crate::{
+    api::{self, RunError},
+    solutions::{solutions, Answer},
+};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+pub fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.execute() {
+        Ok(exit_code) => exit_code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl Cli {
+    pub fn execute(self) -> Result<ExitCode> {
+        let all_profiles = matches!(
+            &self.command,
+            Command::Run(args) | Command::Check(args) if args.all_profiles
+        );
+        if all_profiles && (self.profile.is_some() || self.session.is_some()) {
+            return Err(
+                "`--all-profiles` cannot be combined with `--profile` or `--session`".into(),
+            );
+        }
+
+        let base_url = self
+            .base_url
+            .or_else(|| std::env::var("AOC_BASE_URL").ok())
+            .unwrap_or_else(|| Client::DEFAULT_BASE_URL.to_owned());
+        let profile = match self.profile {
+            Some(name) => Profile::load(&name, self.session)?,
+            None => Profile::new(self.session),
+        };
+        self.command.execute(&Client::new(base_url), &profile)
+    }
+}
+
+impl Command {
+    pub fn execute(self, client: &Client, profile: &Profile) -> Result<ExitCode> {
+        match self {
+            Command::Run(args) => run(args, client, profile),
+            Command::Check(args) => check(args, client, profile),
+            Command::Bench(args) => bench(args, client, profile),
+            Command::Submit(args) => submit(args, client, profile),
+            Command::Fetch(args) => fetch(args, client, profile),
+            Command::List(args) => list(args),
+            Command::New(args) => new(args),
+            Command::Test(args) => test(args),
+            Command::Worker(args) => isolate::worker(args),
+        }
+    }
+}
+
+fn run(args: RunArgs, client: &Client, profile: &Profile) -> Result<ExitCode> {
+    if args.input.is_some() && args.days.start() != args.days.end() {
+        return Err("`--input` can only be used to run a single day".into());
+    }
+    // A single part prints its answer on its own, unless it has to be reported as a record
+    if let (true, Some(part), false, Format::Text) = (
+        args.days.start() == args.days.end(),
+        args.part,
+        args.all_profiles,
+        args.format,
+    ) {
+        let (path, variant, limits) = (args.input, args.variant, args.limits);
+        let args = PartArgs {
+            year: args.year,
+            day: *args.days.start(),
+            part,
+        };
+        let variant = find_variant(args, variant.as_deref())?;
+        let input = match &path {
+            Some(path) => inputs::read(path)?,
+            None => inputs::load(client, profile, args.year, args.day)?,
+        };
+        solve(args, variant, limits, &input)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    for_profiles(&args, profile, |profile| {
+        let rows = run_days(&args, client, profile, false)?;
+        eprint!("{}", report::table(&rows));
+        eprint!("{}", report::failures(&rows));
+        print_records(args.format, profile, &rows)?;
+        Ok(rows
+            .iter()
+            .all(|row| !matches!(row.outcome, Outcome::NotImplemented | Outcome::Failed(_))))
+    })
+}
+
+fn check(args: RunArgs, client: &Client, profile: &Profile) -> Result<ExitCode> {
+    if args.input.is_some() {
+        return Err(
+            "`--input` cannot be checked, as recorded answers are for the cached inputs".into(),
+        );
+    }
+    for_profiles(&args, profile, |profile| {
+        check_profile(&args, client, profile)
+    })
+}
+
+/// Runs `f` for the selected profile, or for every profile with `--all-profiles`, carrying on
+/// past profiles that fail so that the others are still reported. `f` returns whether the profile
+/// passed.
+fn for_profiles(
+    args: &RunArgs,
+    profile: &Profile,
+    mut f: impl FnMut(&Profile) -> Result<bool>,
+) -> Result<ExitCode> {
+    if !args.all_profiles {
+        return Ok(if f(profile)? {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
+    let profiles = Profile::all()?;
+    let mut failed = Vec::new();
+    for profile in &profiles {
+        eprintln!("Profile `{}`:", profile.name());
+        match f(profile) {
+            Ok(true) => {}
+            Ok(false) => failed.push(profile.name()),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed.push(profile.name());
+            }
+        }
+        eprintln!();
+    }
+
+    if failed.is_empty() {
+        eprintln!("All {} profiles passed", profiles.len());
+        Ok(ExitCode::SUCCESS)
+    } else {
+        eprintln!(
+            "{} of {} profiles failed: {}",
+            failed.len(),
+            profiles.len(),
+            failed.join(", ")
+        );
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+/// Compares a profile's answers to those recorded for it, and those of every variant to the
+/// default one, returning whether none were incorrect.
+fn check_profile(args: &RunArgs, client: &Client, profile: &Profile) -> Result<bool> {
+    let answers = Answers::load(profile, args.year)?;
+    let mut rows = run_days(args, client, profile, true)?;
+    let defaults = rows
+        .iter()
+        .filter(|row| row.variant.is_none())
+        .filter_map(|row| Some(((row.day, row.part), row.outcome.answer()?.clone())))
+        .collect::<HashMap<_, _>>();
+
+    let (mut correct, mut incorrect, mut unknown, mut disagreeing) = (0, 0, 0, 0);
+    for row in &mut rows {
+        let Outcome::Answer(answer) = &row.outcome else {
+            continue;
+        };
+        let Some(expected) = answers.get(row.day, row.part) else {
+            // Without a recorded answer, variants can still be checked against the default one
+            match (row.variant, defaults.get(&(row.day, row.part))) {
+                (Some(_), Some(default)) if default != answer => {
+                    disagreeing += 1;
+                    row.outcome = Outcome::Incorrect {
+                        answer: answer.clone(),
+                        expected: default.to_string(),
+                    };
+                }
+                _ => unknown += 1,
+            }
+            continue;
+        };
+
+        if answer.matches(expected) {
+            correct += 1;
+            row.outcome = Outcome::Correct(answer.clone());
+        } else {
+            incorrect += 1;
+            row.outcome = Outcome::Incorrect {
+                answer: answer.clone(),
+                expected: expected.to_owned(),
+            };
+        }
+    }
+
+    let failed = rows
+        .iter()
+        .filter(|row| matches!(row.outcome, Outcome::Failed(_)))
+        .count();
+    eprint!("{}", report::table(&rows));
+    eprint!("{}", report::failures(&rows));
+    eprintln!(
+        "{correct} correct, {incorrect} incorrect, {unknown} without an answer in `{}`",
+        answers.path().display()
+    );
+    if disagreeing > 0 {
+        eprintln!("{disagreeing} variants disagree with the default one, whose answer is expected");
+    }
+    print_records(args.format, profile, &rows)?;
+    Ok(incorrect == 0 && disagreeing == 0 && failed == 0)
+}
+
+fn print_records(format: Format, profile: &Profile, rows: &[Row]) -> Result<()> {
+    if format == Format::Json {
+        for row in rows {
+            Record::new(profile, row).print()?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs the selected parts of every selected day, loading each of the profile's inputs once and
+/// running each day in a worker that parses it once, where a part failing does not stop the
+/// others.
+///
+/// Parts run their default variant, or only the one selected with `--variant`, which skips parts
+/// without it. `all_variants` runs every variant of each part instead.
+fn run_days(
+    args: &RunArgs,
+    client: &Client,
+    profile: &Profile,
+    all_variants: bool,
+) -> Result<Vec<Row>> {
+    let solutions = solutions();
+    // A single day or part has to exist, while ranges skip those that have not been added
+    let (first, last) = (*args.days.start(), *args.days.end());
+    if first == last {
+        match args.part {
+            Some(part) => solutions.part(args.year, first, part)?,
+            None => solutions.day(args.year, first)?,
+        };
+    }
+
+    let mut rows = Vec::new();
+    for (day, solution) in solutions.days(args.year) {
+        if !args.days.contains(&day) {
+            continue;
+        }
+        let parts = args
+            .parts()
+            .filter(|&part| solution.has_part(part))
+            .map(|part| {
+                let variants = match &args.variant {
+                    Some(name) => solution.variant(part, name).into_iter().collect(),
+                    None if all_variants => (0..solution.variants(part).count()).collect(),
+                    None => vec![0],
+                };
+                (part, variants)
+            })
+            .collect::<Vec<(i32, Vec<usize>)>>();
+        // Stub days are skipped before loading their input, so that they are never downloaded
+        let input = if !parts.iter().any(|(part, variants)| {
+            variants
+                .iter()
+                .any(|&variant| solution.is_variant_implemented(*part, variant))
+        }) {
+            None
+        } else if let Some(path) = &args.input {
+            Some(inputs::read(path)?)
+        } else {
+            Some(inputs::load(client, profile, args.year, day)?)
+        };
+
+        // The implemented variants all run in one worker, which parses the input once
+        let jobs = parts
+            .iter()
+            .flat_map(|(part, variants)| {
+                variants
+                    .iter()
+                    .filter(|&&variant| solution.is_variant_implemented(*part, variant))
+                    .map(|&variant| Job {
+                        part: *part,
+                        variant,
+                    })
+            })
+            .collect::<Vec<_>>();
+        let runs = match &input {
+            Some(input) => isolate::run(args.year, day, &jobs, args.limits, input)?,
+            None => DayRun {
+                parse_time: Duration::ZERO,
+                runs: Vec::new(),
+            },
+        };
+        // Parsing is shared by the parts, so its time is counted towards the first one answered
+        let mut parse_time = Some(runs.parse_time);
+        let mut outcomes = runs.runs.into_iter();
+
+        for (part, variants) in parts {
+            for variant in variants {
+                let (outcome, elapsed) = if solution.is_variant_implemented(part, variant) {
+                    match outcomes.next().unwrap() {
+                        Ok(run) => (
+                            Outcome::Answer(run.answer),
+                            run.solve_time + parse_time.take().unwrap_or_default(),
+                        ),
+                        Err(failure) => (Outcome::Failed(failure), Duration::ZERO),
+                    }
+                } else {
+                    (Outcome::NotImplemented, Duration::ZERO)
+                };
+                rows.push(Row {
+                    year: args.year,
+                    day,
+                    part,
+                    variant: (variant > 0).then(|| solution.variants(part).nth(variant).unwrap()),
+                    outcome,
+                    elapsed,
+                });
+            }
+        }
+    }
+
+    if rows.is_empty() {
+        return Err(match &args.variant {
+            Some(name) => format!("none of the selected parts have a variant `{name}`"),
+            None => format!("no solutions for {} days {first} to {last}", args.year),
+        }
+        .into());
+    }
+    Ok(rows)
+}
+
+fn test(args: TestArgs) -> Result<ExitCode> {
+    let TestArgs { part: args, limits } = args;
+    find_variant(args, None)?;
+    let path = format!("inputs/{}/{}.test.txt", args.year, args.day);
+    let input = std::fs::read_to_string(&path)
+        .map_err(|e| format!("could not read example input `{path}`: {e}"))?;
+    solve(args, 0, limits, &input)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(args: SubmitArgs, client: &Client, profile: &Profile) -> Result<ExitCode> {
+    let SubmitArgs {
+        part: args,
+        wait,
+        limits,
+    } = args;
+    find_variant(args, None)?;
+    let token = profile.token()?;
+    let input = inputs::load(client, profile, args.year, args.day)?;
+    let answer = solve(args, 0, limits, &input)?;
+    let Some(submission) = answer.submission() else {
+        return Err(match answer {
+            Answer::Art(_) => "answers drawn as ASCII art have to be read and submitted by hand",
+            _ => "there is no answer to submit",
+        }
+        .into());
+    };
+
+    let mut submissions = Submissions::load(profile, args.year)?;
+    if let Some(accepted) = submissions.accepted(args.day, args.part) {
+        if !answer.matches(accepted) {
+            return Err(format!("not submitting, `{accepted}` was already accepted").into());
+        }
+        eprintln!("`{accepted}` was already accepted");
+        record_answer(profile, args, &answer, submission)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+    if let Some(reason) = submissions.rule_out(args.day, args.part, &answer) {
+        return Err(format!("not submitting, {reason}").into());
+    }
+
+    let (verdict, cooldown) = loop {
+        let cooldown = verdicts::cooldown(profile, verdicts::now())?;
+        if cooldown > 0 && !wait {
+            return Err(format!(
+                "not submitting, wait {} before trying again or pass `--wait`",
+                verdicts::format_wait(cooldown)
+            )
+            .into());
+        } else if cooldown > 0 {
+            eprintln!("Waiting {} to submit...", verdicts::format_wait(cooldown));
+            std::thread::sleep(Duration::from_secs(cooldown));
+        }
+
+        let submitted_at = verdicts::now();
+        let (verdict, cooldown) =
+            client.submit(&token, args.year, args.day, args.part, &submission)?;
+        submissions.push(
+            args.day,
+            args.part,
+            Submission {
+                answer: submission.clone(),
+                verdict,
+                submitted_at,
+                wait: cooldown,
+            },
+        );
+        submissions.save()?;
+
+        if verdict != Verdict::RateLimited || !wait {
+            break (verdict, cooldown);
+        }
+    };
+
+    match verdict {
+        Verdict::Correct => {
+            eprintln!("Answer accepted");
+            record_answer(profile, args, &answer, submission)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Verdict::AlreadyCompleted => {
+            eprintln!("The part was already completed, so the answer was not checked");
+            Ok(ExitCode::SUCCESS)
+        }
+        Verdict::RateLimited => Err(format!(
+            "rate limited, wait {} before trying again or pass `--wait`",
+            verdicts::format_wait(cooldown)
+        )
+        .into()),
+        verdict => {
+            eprintln!(
+                "That's not the right answer ({verdict}), wait {} before trying again",
+                verdicts::format_wait(cooldown)
+            );
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+/// Records an accepted answer in the profile's `answers/<year>.toml` for `check`.
+fn record_answer(
+    profile: &Profile,
+    args: PartArgs,
+    answer: &Answer,
+    submission: String,
+) -> Result<()> {
+    // The server also accepts answers for parts that were already completed, so a different
+    // answer that was recorded earlier is kept rather than overwritten.
+    let mut answers = Answers::load(profile, args.year)?;
+    match answers.get(args.day, args.part) {
+        Some(recorded) if answer.matches(recorded) => {}
+        Some(recorded) => eprintln!("Not recording answer, `{recorded}` was recorded earlier"),
+        None => {
+            answers.set(args.day, args.part, submission);
+            answers.save()?;
+            eprintln!("Answer recorded in `{}`", answers.path().display());
+        }
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs, client: &Client, profile: &Profile) -> Result<ExitCode> {
+    let BenchArgs {
+        part: args,
+        warmup,
+        time,
+        iterations,
+        label,
+        compare,
+        input,
+        variant,
+        format,
+    } = args;
+    let solutions = solutions();
+    let solution = solutions.part(args.year, args.day, args.part)?;
+    // Every implemented variant is benchmarked unless one is selected, so that they can be compared
+    let variants = match &variant {
+        Some(name) => vec![find_variant(args, Some(name))?],
+        None => (0..solution.variants(args.part).count())
+            .filter(|&variant| solution.is_variant_implemented(args.part, variant))
+            .collect(),
+    };
+    if variants.is_empty() {
+        return Err(RunError::NotImplemented {
+            year: args.year,
+            day: args.day,
+            part: args.part,
+        }
+        .into());
+    }
+
+    let input_given = input.is_some();
+    let input = match &input {
+        Some(path) => inputs::read(path)?,
+        None => inputs::load(client, profile, args.year, args.day)?,
+    };
+
+    let measure = match iterations {
+        Some(n) => Measure::Iterations(n),
+        None => Measure::Time(time),
+    };
+    let parse_stats = solution.has_parse().then(|| {
+        eprintln!("Benchmarking {} day {} parsing...", args.year, args.day);
+        let stats = bench::bench(
+            || {
+                black_box(solution.parse(black_box(&input)));
+            },
+            warmup,
+            measure,
+        );
+        eprintln!("{stats}");
+        stats
+    });
+
+    let parsed = solution.parse(&input);
+    let mut results = Vec::new();
+    for &variant in &variants {
+        let name = solution.variants(args.part).nth(variant).unwrap();
+        // The default variant keeps its plain name unless it is being compared to others
+        let described = if variants.len() > 1 || variant > 0 {
+            format!(" (`{name}`)")
+        } else {
+            String::new()
+        };
+        // Timing a part that gives up on the input early would be meaningless
+        let answer = parsed.solve_variant(args.part, variant).map_err(|e| {
+            format!(
+                "{} day {} part {}{described} rejected the input: {e}",
+                args.year, args.day, args.part
+            )
+        })?;
+        eprintln!(
+            "Benchmarking {} day {} part {}{described}...",
+            args.year, args.day, args.part
+        );
+        let stats = bench::bench(
+            || {
+                black_box(parsed.solve_variant(black_box(args.part), variant));
+            },
+            warmup,
+            measure,
+        );
+        eprint!("{stats}");
+        results.push(((variant > 0).then_some(name), name, stats, answer));
+    }
+    if results.len() > 1 {
+        let comparison = results
+            .iter()
+            .map(|&(_, name, stats, _)| (name, stats))
+            .collect::<Vec<_>>();
+        eprint!("\n{}", report::comparison_table(&comparison));
+    }
+
+    if format == Format::Json {
+        for (key, _, stats, answer) in &results {
+            let row = Row {
+                year: args.year,
+                day: args.day,
+                part: args.part,
+                variant: *key,
+                outcome: Outcome::Answer(answer.clone()),
+                elapsed: stats.mean,
+            };
+            Record {
+                stats: Some(*stats),
+                parse_stats,
+                ..Record::new(profile, &row)
+            }
+            .print()?;
+        }
+    }
+
+    let mut baselines = Baselines::load()?;
+    if let Some(compare) = compare {
+        for &(key, name, stats, _) in &results {
+            let prefix = if results.len() > 1 {
+                format!("`{name}` compared")
+            } else {
+                "Compared".to_owned()
+            };
+            let Some(baseline) = baselines.get(&compare, args.year, args.day, args.part, key)
+            else {
+                let missing = format!(
+                    "no baseline `{compare}` for {} day {} part {} in `{}`",
+                    args.year,
+                    args.day,
+                    args.part,
+                    baselines.path().display()
+                );
+                // Variants added since the baseline was saved do not stop the others comparing
+                if results.len() > 1 {
+                    eprintln!("`{name}`: {missing}");
+                    continue;
+                }
+                return Err(missing.into());
+            };
+            eprintln!(
+                "{prefix} to `{compare}` ({:.2?} ± {:.2?}): {}",
+                baseline.mean,
+                baseline.std_dev,
+                Comparison::new(baseline, &stats)
+            );
+        }
+    }
+
+    // Results for another input would be misleading under a commit, so they need a label
+    let label = if input_given {
+        label
+    } else {
+        label.or_else(|| baselines::git_label(Path::new(".")))
+    };
+    match label {
+        Some(label) => {
+            for &(key, _, stats, _) in &results {
+                baselines.insert(&label, args.year, args.day, args.part, key, stats);
+            }
+            baselines.save()?;
+            eprintln!("Saved as `{label}` in `{}`", baselines.path().display());
+        }
+        None if input_given => {
+            eprintln!("Not saving results for `--input`, pass `--label` to save them")
+        }
+        None => eprintln!("Not saving results, pass `--label` outside of a git repository"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn fetch(args: FetchArgs, client: &Client, profile: &Profile) -> Result<ExitCode> {
+    // The session is only read once an input needs downloading, so that fetching cached inputs
+    // works offline
+    let mut token = None;
+    let mut failed = 0;
+    for day in args.days.clone() {
+        let path = profile.input(args.year, day);
+        let cached = inputs::cached(profile, args.year, day)?;
+        if let (Cached::Valid(_), false) = (&cached, args.force) {
+            eprintln!("{} day {day}: already in `{}`", args.year, path.display());
+            continue;
+        }
+        if let Cached::Invalid(reason) = cached {
+            eprintln!("{} day {day}: `{}` {reason}", args.year, path.display());
+        }
+
+        let token = match &token {
+            Some(token) => token,
+            None => token.insert(
+                profile
+                    .token()
+                    .map_err(|e| format!("{} day {day} needs downloading, and {e}", args.year))?,
+            ),
+        };
+        match inputs::download(client, profile, token, args.year, day) {
+            Ok(_) => eprintln!("{} day {day}: saved to `{}`", args.year, path.display()),
+            Err(e) => {
+                eprintln!("{} day {day}: {e}", args.year);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("could not download {failed} inputs").into());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn list(args: ListArgs) -> Result<ExitCode> {
+    let solutions = solutions();
+    let years = solutions
+        .years()
+        .into_iter()
+        .filter(|&year| args.year.is_none() || args.year == Some(year))
+        .map(|year| {
+            let days = solutions
+                .days(year)
+                .map(|(day, solution)| {
+                    let part = |part| {
+                        solution
+                            .has_part(part)
+                            .then(|| solution.is_implemented(part))
+                    };
+                    (day, [part(1), part(2)])
+                })
+                .collect::<Vec<_>>();
+            (year, days)
+        })
+        .collect::<Vec<_>>();
+
+    eprint!("{}", report::status_table(&years));
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new(args: NewArgs) -> Result<ExitCode> {
+    let created = scaffold::scaffold(Path::new(""), args.year, args.day)?;
+    if created.is_empty() {
+        eprintln!("Nothing to do, the files already exist");
+    }
+    for path in &created {
+        eprintln!("Created `{}`", path.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Finds the index of the part's variant called `name`, or of the default one for `None`, and
+/// checks that it is implemented.
+fn find_variant(args: PartArgs, name: Option<&str>) -> Result<usize> {
+    let (_, variant) = api::lookup(args.year, args.day, args.part, name)?;
+    Ok(variant)
+}
+
+/// Runs a variant of a part in a worker, printing its answer and how long it took to parse the
+/// input and calculate it. The variant is one returned by `find_variant`.
+fn solve(args: PartArgs, variant: usize, limits: Limits, input: &str) -> Result<Answer> {
+    let solution = solutions().part(args.year, args.day, args.part)?;
+
+    let job = Job {
+        part: args.part,
+        variant,
+    };
+    let mut day_run = isolate::run(args.year, args.day, &[job], limits, input)?;
+    let run = day_run.runs.pop().unwrap().map_err(|failure| {
+        format!(
+            "{} day {} part {} {failure}",
+            args.year, args.day, args.part
+        )
+    })?;
+    if solution.has_parse() {
+        eprintln!("Parsed in: {:?}", day_run.parse_time);
+    }
+    eprintln!("Calculated in: {:?}", run.solve_time);
+    match &run.answer {
+        Answer::Art(art) => eprintln!("Answer:\n{art}"),
+        Answer::None => {}
+        answer => eprintln!("Answer: {answer}"),
+    }
+    Ok(run.answer)
+}
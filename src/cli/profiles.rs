@@ -0,0 +1,236 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use super::{Result, Session};
+
+/// Whose inputs to run against: where an account's inputs, answers and submissions are stored
+/// and which session token downloads and submits them.
+///
+/// The default profile uses `inputs/`, `answers/` and `submissions/`. Named profiles are
+/// configured in `profiles.toml`, where every setting is optional and defaults to a directory
+/// under `profiles/<name>/`:
+///
+/// ```toml
+/// [alice]
+/// inputs = "profiles/alice/inputs"
+/// answers = "profiles/alice/answers"
+/// submissions = "profiles/alice/submissions"
+/// # A file containing the token, instead of `aoc-rs/sessions/alice` in the config directory
+/// session = "/home/alice/.aoc-session"
+/// ```
+#[derive(Debug, Clone)]
+pub struct Profile {
+    name: Option<String>,
+    inputs: PathBuf,
+    answers: PathBuf,
+    submissions: PathBuf,
+    session: Session,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    inputs: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    submissions: Option<PathBuf>,
+    session: Option<PathBuf>,
+}
+
+impl Profile {
+    pub const CONFIG_PATH: &'static str = "profiles.toml";
+
+    /// The default profile, with the token from `--session` if it was given.
+    pub fn new(session: Option<String>) -> Self {
+        Self {
+            name: None,
+            inputs: PathBuf::from("inputs"),
+            answers: PathBuf::from("answers"),
+            submissions: PathBuf::from("submissions"),
+            session: Session::new(session),
+        }
+    }
+
+    /// The profile called `name` in `profiles.toml`, with the token from `--session` if it was
+    /// given.
+    pub fn load(name: &str, session: Option<String>) -> Result<Self> {
+        let mut configs = load_configs(Path::new(Self::CONFIG_PATH))?;
+        let Some(config) = configs.remove(name) else {
+            let names = configs.keys().cloned().collect::<Vec<_>>();
+            return Err(if names.is_empty() {
+                format!("no profile `{name}`, as `{}` has none", Self::CONFIG_PATH)
+            } else {
+                format!("no profile `{name}`, expected one of {}", names.join(", "))
+            }
+            .into());
+        };
+        Ok(Self::from_config(name, config, session))
+    }
+
+    /// Every profile in `profiles.toml`.
+    pub fn all() -> Result<Vec<Self>> {
+        let configs = load_configs(Path::new(Self::CONFIG_PATH))?;
+        if configs.is_empty() {
+            return Err(format!("no profiles configured in `{}`", Self::CONFIG_PATH).into());
+        }
+        Ok(configs
+            .into_iter()
+            .map(|(name, config)| Self::from_config(&name, config, None))
+            .collect())
+    }
+
+    fn from_config(name: &str, config: Config, session: Option<String>) -> Self {
+        let dir = Path::new("profiles").join(name);
+        Self {
+            name: Some(name.to_owned()),
+            inputs: config.inputs.unwrap_or_else(|| dir.join("inputs")),
+            answers: config.answers.unwrap_or_else(|| dir.join("answers")),
+            submissions: config
+                .submissions
+                .unwrap_or_else(|| dir.join("submissions")),
+            session: Session::for_profile(session, name, config.session),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or("default")
+    }
+
+    pub fn input(&self, year: i32, day: i32) -> PathBuf {
+        self.inputs
+            .join(year.to_string())
+            .join(format!("{day}.txt"))
+    }
+
+    pub fn answers(&self, year: i32) -> PathBuf {
+        self.answers.join(format!("{year}.toml"))
+    }
+
+    pub fn submissions(&self, year: i32) -> PathBuf {
+        self.submissions.join(format!("{year}.toml"))
+    }
+
+    /// The `submissions/<year>.toml` log of every year that has one.
+    pub fn submission_logs(&self) -> Result<Vec<PathBuf>> {
+        let entries = match std::fs::read_dir(&self.submissions) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                let dir = self.submissions.display();
+                return Err(format!("could not read `{dir}`: {e}").into());
+            }
+        };
+
+        let mut logs = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let is_year = path.extension().is_some_and(|ext| ext == "toml")
+                && path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str()?.parse::<i32>().ok())
+                    .is_some();
+            if is_year {
+                logs.push(path);
+            }
+        }
+        logs.sort();
+        Ok(logs)
+    }
+
+    /// Resolves the profile's session token, which is only done when a request needs it.
+    pub fn token(&self) -> Result<String> {
+        self.session.token()
+    }
+}
+
+/// Reads the profiles configured in `path`, which is treated as empty if it does not exist.
+fn load_configs(path: &Path) -> Result<BTreeMap<String, Config>> {
+    let configs: BTreeMap<String, Config> = match std::fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents)
+            .map_err(|e| format!("could not parse `{}`: {e}", path.display()))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+        Err(e) => return Err(format!("could not read `{}`: {e}", path.display()).into()),
+    };
+
+    if let Some(name) = configs.keys().find(|name| {
+        name.is_empty()
+            || !name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    }) {
+        return Err(format!(
+            "`{name}` in `{}` is not a valid profile name, expected letters, digits, `-` and `_`",
+            path.display()
+        )
+        .into());
+    }
+    Ok(configs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn paths() {
+        let profile = Profile::new(None);
+        assert_eq!(profile.name(), "default");
+        assert_eq!(profile.input(2023, 5), Path::new("inputs/2023/5.txt"));
+        assert_eq!(profile.answers(2023), Path::new("answers/2023.toml"));
+        assert_eq!(
+            profile.submissions(2023),
+            Path::new("submissions/2023.toml")
+        );
+
+        let dir = TempDir::new("profiles");
+        let path = dir.join("profiles.toml");
+        std::fs::write(&path, "[alice]\n\n[bob]\ninputs = \"../bob/inputs\"\n").unwrap();
+
+        let mut configs = load_configs(&path).unwrap();
+        assert_eq!(configs.keys().collect::<Vec<_>>(), ["alice", "bob"]);
+        let alice = Profile::from_config("alice", configs.remove("alice").unwrap(), None);
+        assert_eq!(alice.name(), "alice");
+        assert_eq!(
+            alice.input(2023, 5),
+            Path::new("profiles/alice/inputs/2023/5.txt")
+        );
+        assert_eq!(
+            alice.answers(2023),
+            Path::new("profiles/alice/answers/2023.toml")
+        );
+        let bob = Profile::from_config("bob", configs.remove("bob").unwrap(), None);
+        assert_eq!(bob.input(2023, 5), Path::new("../bob/inputs/2023/5.txt"));
+        assert_eq!(
+            bob.submissions(2023),
+            Path::new("profiles/bob/submissions/2023.toml")
+        );
+
+        std::fs::write(&path, "[alice]\ninput = \"typo\"\n").unwrap();
+        assert!(load_configs(&path).is_err());
+        std::fs::write(&path, "[\"../alice\"]\n").unwrap();
+        assert!(load_configs(&path)
+            .unwrap_err()
+            .to_string()
+            .contains("not a valid profile name"));
+        assert!(load_configs(&dir.join("missing.toml")).unwrap().is_empty());
+
+        let config = Config {
+            submissions: Some(dir.join("submissions")),
+            ..Config::default()
+        };
+        let carol = Profile::from_config("carol", config, None);
+        assert!(carol.submission_logs().unwrap().is_empty());
+        std::fs::create_dir_all(dir.join("submissions")).unwrap();
+        for file in ["2023.toml", "2022.toml", "notes.toml", "2021.txt"] {
+            std::fs::write(dir.join("submissions").join(file), "").unwrap();
+        }
+        assert_eq!(
+            carol.submission_logs().unwrap(),
+            [carol.submissions(2022), carol.submissions(2023)]
+        );
+    }
+}
@@ -0,0 +1,522 @@
+use std::{fmt::Write, time::Duration};
+
+use serde::Serialize;
+
+use super::{Failure, Profile, Result, Stats};
+use crate::solutions::Answer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(Answer),
+    /// The answer matched the recorded one.
+    Correct(Answer),
+    /// The answer differed from the recorded one.
+    Incorrect {
+        answer: Answer,
+        expected: String,
+    },
+    NotImplemented,
+    /// The part panicked, timed out or ran out of memory.
+    Failed(Failure),
+}
+
+impl Outcome {
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Outcome::Answer(answer)
+            | Outcome::Correct(answer)
+            | Outcome::Incorrect { answer, .. } => Some(answer),
+            Outcome::NotImplemented | Outcome::Failed(_) => None,
+        }
+    }
+
+    fn describe(&self) -> String {
+        // Art spans several lines, so it is printed below the table instead
+        let inline = |answer: &Answer| match answer {
+            Answer::Art(_) => "ASCII art, see below".to_owned(),
+            Answer::None => "no answer".to_owned(),
+            answer => answer.to_string(),
+        };
+
+        match self {
+            Outcome::Answer(answer) => inline(answer),
+            Outcome::Correct(answer) => format!("{} (correct)", inline(answer)),
+            Outcome::Incorrect { answer, expected } => {
+                format!("{} (expected {expected})", inline(answer))
+            }
+            Outcome::NotImplemented => "not implemented".to_owned(),
+            Outcome::Failed(failure) => failure.short(),
+        }
+    }
+}
+
+/// The outcome of running a single part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    /// The variant of the part that was run, or `None` for the default one.
+    pub variant: Option<&'static str>,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// How running a part went, as written by `--format json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The part ran, but there was no recorded answer to compare with.
+    Answered,
+    Correct,
+    Incorrect,
+    NotImplemented,
+    /// The part panicked, timed out or ran out of memory, as described by `error`.
+    Failed,
+}
+
+/// A part's outcome as written to stdout by `--format json`, one object per line.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub profile: String,
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    /// Parts without an answer have `null`, and art is given as its lines joined by newlines.
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// How long the part took, or its mean time when benchmarking.
+    pub duration_ns: Option<u64>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Stats>,
+    /// The statistics of parsing the input, for days that parse it separately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_stats: Option<Stats>,
+}
+
+impl Record {
+    pub fn new(profile: &Profile, row: &Row) -> Self {
+        let (status, expected, error) = match &row.outcome {
+            Outcome::Answer(_) => (Status::Answered, None, None),
+            Outcome::Correct(_) => (Status::Correct, None, None),
+            Outcome::Incorrect { expected, .. } => {
+                (Status::Incorrect, Some(expected.clone()), None)
+            }
+            Outcome::NotImplemented => (Status::NotImplemented, None, None),
+            Outcome::Failed(failure) => (Status::Failed, None, Some(failure.to_string())),
+        };
+        Self {
+            profile: profile.name().to_owned(),
+            year: row.year,
+            day: row.day,
+            part: row.part,
+            variant: row.variant.map(str::to_owned),
+            answer: row
+                .outcome
+                .answer()
+                .filter(|answer| **answer != Answer::None)
+                .map(ToString::to_string),
+            expected,
+            error,
+            duration_ns: matches!(
+                status,
+                Status::Answered | Status::Correct | Status::Incorrect
+            )
+            .then_some(row.elapsed.as_nanos() as u64),
+            status,
+            stats: None,
+            parse_stats: None,
+        }
+    }
+
+    /// Writes the record to stdout as a single line of JSON.
+    pub fn print(&self) -> Result<()> {
+        println!("{}", serde_json::to_string(self)?);
+        Ok(())
+    }
+}
+
+/// Formats `rows` as a table with one line per part, followed by the total time taken and any
+/// answers drawn as ASCII art.
+pub fn table(rows: &[Row]) -> String {
+    let parts = rows.iter().map(Row::part_label).collect::<Vec<_>>();
+    let part_width = parts
+        .iter()
+        .map(|part| part.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Part".len());
+    let answer_width = rows
+        .iter()
+        .map(|row| row.outcome.describe().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    let elapsed = rows
+        .iter()
+        .map(|row| match row.outcome {
+            Outcome::NotImplemented | Outcome::Failed(_) => "-".to_owned(),
+            _ => format!("{:.2?}", row.elapsed),
+        })
+        .collect::<Vec<_>>();
+    let total = format!(
+        "{:.2?}",
+        rows.iter().map(|row| row.elapsed).sum::<Duration>()
+    );
+    let elapsed_width = elapsed
+        .iter()
+        .chain([&total])
+        .map(|elapsed| elapsed.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Time".len());
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "Year  Day  {:>part_width$}  {:<answer_width$}  {:>elapsed_width$}",
+        "Part", "Answer", "Time"
+    )
+    .unwrap();
+    for ((row, part), elapsed) in rows.iter().zip(&parts).zip(&elapsed) {
+        writeln!(
+            out,
+            "{:<4}  {:>3}  {:>part_width$}  {:<answer_width$}  {:>elapsed_width$}",
+            row.year,
+            row.day,
+            part,
+            row.outcome.describe(),
+            elapsed
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        out,
+        "{:<width$}  {:>elapsed_width$}",
+        "Total",
+        total,
+        width = "Year  Day    ".len() + part_width + answer_width
+    )
+    .unwrap();
+
+    for (row, part) in rows.iter().zip(&parts) {
+        if let Some(Answer::Art(art)) = row.outcome.answer() {
+            writeln!(out, "\n{} day {} part {part}:\n{art}", row.year, row.day).unwrap();
+        }
+    }
+    out
+}
+
+/// Lists the parts that failed with what happened to each, for after the table.
+pub fn failures(rows: &[Row]) -> String {
+    let failed = rows
+        .iter()
+        .filter_map(|row| match &row.outcome {
+            Outcome::Failed(failure) => Some((row, failure)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if failed.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("\n{} parts failed:\n", failed.len());
+    for (row, failure) in failed {
+        writeln!(
+            out,
+            "{} day {} part {}: {}",
+            row.year,
+            row.day,
+            row.part_label(),
+            failure.to_string().replace('\n', " ")
+        )
+        .unwrap();
+    }
+    out
+}
+
+impl Row {
+    /// The part, followed by the variant that was run unless it is the default one.
+    fn part_label(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("{} ({variant})", self.part),
+            None => self.part.to_string(),
+        }
+    }
+}
+
+/// Formats the benchmarks of a part's variants side by side, with how many times slower than the
+/// fastest one each is.
+pub fn comparison_table(results: &[(&str, Stats)]) -> String {
+    let fastest = results
+        .iter()
+        .map(|(_, stats)| stats.mean)
+        .min()
+        .unwrap_or_default();
+    let rows = results
+        .iter()
+        .map(|(name, stats)| {
+            let ratio = stats.mean.as_secs_f64() / fastest.as_secs_f64();
+            (
+                *name,
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.std_dev),
+                format!("{ratio:.2}x"),
+            )
+        })
+        .collect::<Vec<_>>();
+    let width = |column: fn(&(&str, String, String, String)) -> usize, header: &str| {
+        rows.iter().map(column).max().unwrap_or(0).max(header.len())
+    };
+    let name_width = width(|row| row.0.chars().count(), "Variant");
+    let mean_width = width(|row| row.1.chars().count(), "Mean");
+    let std_dev_width = width(|row| row.2.chars().count(), "Std. dev.");
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:<name_width$}  {:>mean_width$}  {:>std_dev_width$}  Relative",
+        "Variant", "Mean", "Std. dev."
+    )
+    .unwrap();
+    for (name, mean, std_dev, ratio) in &rows {
+        writeln!(
+            out,
+            "{name:<name_width$}  {mean:>mean_width$}  {std_dev:>std_dev_width$}  {ratio:>8}"
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// A registered day, and whether each of its parts is implemented or `None` if it does not have
+/// that part.
+pub type DayStatus = (i32, [Option<bool>; 2]);
+
+/// Formats which parts of each registered day are implemented, followed by a count for each year.
+pub fn status_table(years: &[(i32, Vec<DayStatus>)]) -> String {
+    let status = |implemented| match implemented {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "-",
+    };
+
+    let mut out = String::new();
+    writeln!(out, "Year  Day  Part 1  Part 2").unwrap();
+    for (year, days) in years {
+        for (day, parts) in days {
+            writeln!(
+                out,
+                "{year:<4}  {day:>3}  {:<6}  {}",
+                status(parts[0]),
+                status(parts[1])
+            )
+            .unwrap();
+        }
+    }
+
+    for (year, days) in years {
+        let parts = days.iter().flat_map(|(_, parts)| parts.iter().flatten());
+        let implemented = parts.clone().filter(|&&part| part).count();
+        writeln!(
+            out,
+            "{year}: {implemented}/{} parts implemented",
+            parts.count()
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records() {
+        let profile = Profile::new(None);
+        let row = |outcome| Row {
+            year: 2023,
+            day: 1,
+            part: 2,
+            variant: None,
+            outcome,
+            elapsed: Duration::from_micros(50),
+        };
+
+        let record = Record::new(&profile, &row(Outcome::Correct(Answer::Integer(281))));
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"profile":"default","year":2023,"day":1,"part":2,"answer":"281","duration_ns":50000,"status":"correct"}"#
+        );
+
+        let record = Record::new(
+            &profile,
+            &row(Outcome::Incorrect {
+                answer: Answer::from("a"),
+                expected: "b".to_owned(),
+            }),
+        );
+        assert_eq!(record.status, Status::Incorrect);
+        assert_eq!(record.expected.as_deref(), Some("b"));
+
+        let record = Record::new(&profile, &row(Outcome::NotImplemented));
+        assert_eq!((record.answer, record.duration_ns), (None, None));
+        assert_eq!(
+            Record::new(&profile, &row(Outcome::Answer(Answer::None))).answer,
+            None
+        );
+
+        let failed = row(Outcome::Failed(Failure::OutOfMemory(16)));
+        let record = Record::new(&profile, &failed);
+        assert_eq!((record.status, record.duration_ns), (Status::Failed, None));
+        assert_eq!(
+            record.error.as_deref(),
+            Some("tried to allocate more than 16 MiB")
+        );
+        assert_eq!(
+            failures(&[row(Outcome::Answer(Answer::Integer(1))), failed]),
+            "\n1 parts failed:\n2023 day 1 part 2: tried to allocate more than 16 MiB\n"
+        );
+    }
+
+    #[test]
+    fn table_alignment() {
+        let rows = [
+            Row {
+                year: 2023,
+                day: 1,
+                part: 1,
+                variant: None,
+                outcome: Outcome::Answer(Answer::Integer(54573)),
+                elapsed: Duration::from_micros(50),
+            },
+            Row {
+                year: 2023,
+                day: 12,
+                part: 2,
+                variant: None,
+                outcome: Outcome::Correct(Answer::Integer(1)),
+                elapsed: Duration::from_millis(12),
+            },
+            Row {
+                year: 2023,
+                day: 12,
+                part: 1,
+                variant: None,
+                outcome: Outcome::Incorrect {
+                    answer: Answer::from("2"),
+                    expected: "3".to_owned(),
+                },
+                elapsed: Duration::ZERO,
+            },
+            Row {
+                year: 2023,
+                day: 13,
+                part: 1,
+                variant: None,
+                outcome: Outcome::NotImplemented,
+                elapsed: Duration::ZERO,
+            },
+            Row {
+                year: 2022,
+                day: 10,
+                part: 2,
+                variant: None,
+                outcome: Outcome::Answer(Answer::Art("#  #\n####".to_owned())),
+                elapsed: Duration::ZERO,
+            },
+        ];
+
+        assert_eq!(
+            table(&rows),
+            "\
+Year  Day  Part  Answer                   Time
+2023    1     1  54573                 50.00µs
+2023   12     2  1 (correct)           12.00ms
+2023   12     1  2 (expected 3)         0.00ns
+2023   13     1  not implemented             -
+2022   10     2  ASCII art, see below   0.00ns
+Total                                  12.05ms
+
+2022 day 10 part 2:
+#  #
+####
+"
+        );
+    }
+
+    #[test]
+    fn variants() {
+        let row = |variant, answer| Row {
+            year: 2023,
+            day: 5,
+            part: 2,
+            variant,
+            outcome: Outcome::Answer(Answer::Integer(answer)),
+            elapsed: Duration::from_micros(10),
+        };
+        let rows = [row(None, 46), row(Some("ranges"), 46)];
+        assert_eq!(
+            table(&rows),
+            "\
+Year  Day        Part  Answer     Time
+2023    5           2  46      10.00µs
+2023    5  2 (ranges)  46      10.00µs
+Total                          20.00µs
+"
+        );
+
+        let record = Record::new(&Profile::new(None), &rows[1]);
+        assert_eq!(record.variant.as_deref(), Some("ranges"));
+        assert!(serde_json::to_string(&record)
+            .unwrap()
+            .contains(r#""part":2,"variant":"ranges","answer":"46""#));
+        assert!(
+            !serde_json::to_string(&Record::new(&Profile::new(None), &rows[0]))
+                .unwrap()
+                .contains("variant")
+        );
+
+        let stats = |micros| Stats::from_samples(vec![Duration::from_micros(micros)]);
+        assert_eq!(
+            comparison_table(&[("default", stats(1500)), ("ranges", stats(10))]),
+            "\
+Variant     Mean  Std. dev.  Relative
+default   1.50ms     0.00ns   150.00x
+ranges   10.00µs     0.00ns     1.00x
+"
+        );
+    }
+
+    #[test]
+    fn status_counts() {
+        let years = [(
+            2023,
+            vec![
+                (1, [Some(true), Some(true)]),
+                (2, [Some(true), Some(false)]),
+                (3, [Some(false), Some(false)]),
+                (25, [Some(true), None]),
+            ],
+        )];
+        assert_eq!(
+            status_table(&years),
+            "\
+Year  Day  Part 1  Part 2
+2023    1  yes     yes
+2023    2  yes     no
+2023    3  no      no
+2023   25  yes     -
+2023: 4/7 parts implemented
+"
+        );
+    }
+}
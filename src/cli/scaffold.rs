@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+
+use super::Result;
+
+/// The stub that new days start from, which the registry reports as not implemented until its
+/// parts return something other than `Unimplemented`.
+const TEMPLATE: &str = r#"use crate::solutions::Unimplemented;
+
+pub fn part1(_input: &str) -> Unimplemented {
+    Unimplemented
+}
+
+pub fn part2(_input: &str) -> Unimplemented {
+    Unimplemented
+}
+
+// Uncomment once the example and its answers are filled in, so that each part is checked
+// against them.
+// #[cfg(test)]
+// const EXAMPLE: &str = "\
+// ";
+//
+// examples! {
+//     part1: [EXAMPLE => 0],
+//     part2: [EXAMPLE => 0],
+// }
+"#;
+
+/// The stub for `day`, leaving out the second part on day 25, which only has one.
+fn template(day: i32) -> String {
+    if day != 25 {
+        return TEMPLATE.to_owned();
+    }
+    TEMPLATE
+        .replace(
+            "\npub fn part2(_input: &str) -> Unimplemented {\n    Unimplemented\n}\n",
+            "",
+        )
+        .replace("//     part2: [EXAMPLE => 0],\n", "")
+}
+
+/// Generates a stub `root/src/solutions/year<year>/day<day>.rs` for `day`, or every day of the
+/// year, that does not exist yet. Existing files are left alone, so running it again changes
+/// nothing, and the build script registers the new files.
+pub fn scaffold(root: &Path, year: i32, day: Option<i32>) -> Result<Vec<PathBuf>> {
+    let solutions = root.join("src").join("solutions");
+    if !solutions.is_dir() {
+        return Err(format!(
+            "`{}` does not exist, run this from the root of the repository",
+            solutions.display()
+        )
+        .into());
+    }
+
+    let dir = solutions.join(format!("year{year}"));
+    let days = match day {
+        Some(day) => day..=day,
+        None => 1..=25,
+    };
+
+    let mut created = Vec::new();
+    for day in days {
+        let path = dir.join(format!("day{day:02}.rs"));
+        if !path.exists() {
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(&path, template(day))
+                .map_err(|e| format!("could not write `{}`: {e}", path.display()))?;
+            created.push(path);
+        }
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn idempotent() {
+        let root = TempDir::new("scaffold");
+        let solutions = root.join("src").join("solutions");
+        assert!(scaffold(root.path(), 2024, None).is_err());
+        std::fs::create_dir_all(&solutions).unwrap();
+
+        let day = solutions.join("year2024").join("day05.rs");
+        assert_eq!(
+            scaffold(root.path(), 2024, Some(5)).unwrap(),
+            std::slice::from_ref(&day)
+        );
+        assert_eq!(std::fs::read_to_string(&day).unwrap(), TEMPLATE);
+        assert!(!TEMPLATE.lines().any(|line| line.starts_with("examples!")));
+
+        std::fs::write(&day, "// solved").unwrap();
+        assert_eq!(scaffold(root.path(), 2024, None).unwrap().len(), 24);
+        assert_eq!(
+            scaffold(root.path(), 2024, None).unwrap(),
+            [] as [PathBuf; 0]
+        );
+        let last = std::fs::read_to_string(solutions.join("year2024").join("day25.rs")).unwrap();
+        assert!(last.contains("pub fn part1(") && !last.contains("part2"));
+        assert_eq!(std::fs::read_to_string(&day).unwrap(), "// solved");
+    }
+}
@@ -0,0 +1,187 @@
+use std::path::PathBuf;
+
+use super::Result;
+
+/// Resolves the session token that authenticates requests to the website, from the first of
+/// these that has one:
+///
+/// 1. the `--session` flag
+/// 2. the `AOC_SESSION` environment variable
+/// 3. `aoc-rs/session` in the user's config directory
+/// 4. `.session` in the working directory or any of its parents
+///
+/// Named profiles instead use the `--session` flag, `AOC_SESSION_<NAME>`, and either the file
+/// configured for the profile or `aoc-rs/sessions/<name>` in the user's config directory.
+///
+/// Sources are only read when a token is needed, so that commands that work from cached inputs
+/// run without one.
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    flag: Option<String>,
+    profile: Option<(String, Option<PathBuf>)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    Flag(Option<String>),
+    Env(String),
+    File(PathBuf),
+}
+
+impl Session {
+    pub fn new(flag: Option<String>) -> Self {
+        Self {
+            flag,
+            profile: None,
+        }
+    }
+
+    /// The session of the profile called `name`, read from `file` if it is configured.
+    pub fn for_profile(flag: Option<String>, name: &str, file: Option<PathBuf>) -> Self {
+        Self {
+            flag,
+            profile: Some((name.to_owned(), file)),
+        }
+    }
+
+    pub fn token(&self) -> Result<String> {
+        let mut sources = vec![Source::Flag(self.flag.clone())];
+        match &self.profile {
+            None => {
+                sources.push(Source::Env("AOC_SESSION".to_owned()));
+                sources.extend(
+                    config_dir().map(|dir| Source::File(dir.join("aoc-rs").join("session"))),
+                );
+                if let Ok(dir) = std::env::current_dir() {
+                    sources.extend(
+                        dir.ancestors()
+                            .map(|dir| Source::File(dir.join(".session"))),
+                    );
+                }
+            }
+            Some((name, file)) => {
+                sources.push(Source::Env(env_var(name)));
+                sources.extend(match file {
+                    Some(file) => Some(Source::File(file.clone())),
+                    None => config_dir()
+                        .map(|dir| Source::File(dir.join("aoc-rs").join("sessions").join(name))),
+                });
+            }
+        }
+        resolve(&sources)
+    }
+}
+
+impl Source {
+    fn describe(&self) -> String {
+        match self {
+            Source::Flag(_) => "`--session`".to_owned(),
+            Source::Env(var) => format!("`{var}`"),
+            Source::File(path) => format!("`{}`", path.display()),
+        }
+    }
+
+    fn read(&self) -> Result<Option<String>> {
+        Ok(match self {
+            Source::Flag(flag) => flag.clone(),
+            Source::Env(var) => std::env::var(var).ok(),
+            Source::File(path) => match std::fs::read_to_string(path) {
+                Ok(token) => Some(token),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(e) => return Err(format!("could not read `{}`: {e}", path.display()).into()),
+            },
+        })
+    }
+}
+
+/// Returns the token from the first source that has one, failing if it is malformed rather than
+/// silently falling back to the next source.
+fn resolve(sources: &[Source]) -> Result<String> {
+    for source in sources {
+        let Some(token) = source.read()? else {
+            continue;
+        };
+
+        let token = token.trim();
+        if token.is_empty() || !token.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(format!(
+                "the session token from {} is not valid, expected the hexadecimal value of the \
+                 `session` cookie",
+                source.describe()
+            )
+            .into());
+        }
+        return Ok(token.to_owned());
+    }
+
+    let tried = sources
+        .iter()
+        .map(Source::describe)
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(format!("no session token found, tried {tried}").into())
+}
+
+/// The environment variable holding a profile's token, such as `AOC_SESSION_ALICE` for `alice`.
+fn env_var(profile: &str) -> String {
+    let suffix = profile
+        .chars()
+        .map(|c| match c {
+            '-' => '_',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect::<String>();
+    format!("AOC_SESSION_{suffix}")
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+    if cfg!(windows) {
+        var("APPDATA").map(PathBuf::from)
+    } else {
+        var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".config")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    #[test]
+    fn resolution_order() {
+        let dir = TempDir::new("session");
+        std::fs::write(dir.join("session"), "  53616c7465\n").unwrap();
+        let file = Source::File(dir.join("session"));
+        let missing = Source::File(dir.join("missing"));
+
+        assert_eq!(
+            resolve(&[Source::Flag(None), missing.clone(), file.clone()]).unwrap(),
+            "53616c7465"
+        );
+        assert_eq!(
+            resolve(&[Source::Flag(Some("abc123".to_owned())), file.clone()]).unwrap(),
+            "abc123"
+        );
+
+        let err = resolve(&[Source::Flag(Some("session=abc".to_owned())), file]).unwrap_err();
+        assert!(err.to_string().contains("from `--session` is not valid"));
+
+        let err = resolve(&[Source::Flag(None), missing]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "no session token found, tried `--session`, `{}`",
+                dir.join("missing").display()
+            )
+        );
+    }
+
+    #[test]
+    fn profile_variables() {
+        assert_eq!(env_var("alice"), "AOC_SESSION_ALICE");
+        assert_eq!(env_var("team-b_2"), "AOC_SESSION_TEAM_B_2");
+    }
+}
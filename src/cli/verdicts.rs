@@ -0,0 +1,387 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Profile, Result};
+use crate::solutions::Answer;
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was not checked, as another one was submitted too recently.
+    RateLimited,
+    /// The answer was not checked, as the part was already solved.
+    AlreadyCompleted,
+}
+
+impl Verdict {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate limited",
+            Verdict::AlreadyCompleted => "already completed",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+    /// How many seconds the server asked to wait before submitting again.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub wait: u64,
+}
+
+fn is_zero(n: &u64) -> bool {
+    *n == 0
+}
+
+/// Every answer submitted for a year and the server's verdict on it, stored in
+/// the profile's `submissions/<year>.toml` as
+///
+/// ```toml
+/// [[day01.part1]]
+/// answer = "54572"
+/// verdict = "too_low"
+/// submitted_at = 1701406800
+/// wait = 60
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Submissions {
+    path: PathBuf,
+    days: BTreeMap<String, DaySubmissions>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DaySubmissions {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn load(profile: &Profile, year: i32) -> Result<Self> {
+        Self::load_from(profile.submissions(year))
+    }
+
+    /// Loads submissions from `path`, which is treated as empty if it does not exist yet.
+    pub fn load_from(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let days = match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| format!("could not parse `{}`: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(format!("could not read `{}`: {e}", path.display()).into()),
+        };
+
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, toml::to_string(&self.days)?)
+            .map_err(|e| format!("could not write `{}`: {e}", self.path.display()))?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: i32, part: i32) -> &[Submission] {
+        let Some(day) = self.days.get(&Self::key(day)) else {
+            return &[];
+        };
+        match part {
+            1 => &day.part1,
+            2 => &day.part2,
+            _ => &[],
+        }
+    }
+
+    pub fn push(&mut self, day: i32, part: i32, submission: Submission) {
+        let day = self.days.entry(Self::key(day)).or_default();
+        match part {
+            1 => day.part1.push(submission),
+            2 => day.part2.push(submission),
+            _ => panic!("part {part} does not exist"),
+        }
+    }
+
+    /// The answer that the server accepted, if any.
+    pub fn accepted(&self, day: i32, part: i32) -> Option<&str> {
+        self.get(day, part)
+            .iter()
+            .find(|submission| submission.verdict == Verdict::Correct)
+            .map(|submission| submission.answer.as_str())
+    }
+
+    /// The longest wait left from any submission in the log, which `cooldown` takes across years.
+    pub fn cooldown(&self, now: u64) -> u64 {
+        self.days
+            .values()
+            .flat_map(|day| day.part1.iter().chain(&day.part2))
+            .map(|submission| (submission.submitted_at + submission.wait).saturating_sub(now))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Explains why `answer` cannot be right, given the verdicts on earlier submissions.
+    pub fn rule_out(&self, day: i32, part: i32, answer: &Answer) -> Option<String> {
+        let submissions = self.get(day, part);
+        if let Some(wrong) = submissions
+            .iter()
+            .find(|submission| submission.verdict.is_wrong() && answer.matches(&submission.answer))
+        {
+            return Some(format!(
+                "`{answer}` was already submitted and was {}",
+                wrong.verdict
+            ));
+        }
+
+        let Answer::Integer(n) = *answer else {
+            return None;
+        };
+        let bound = |verdict| {
+            submissions
+                .iter()
+                .filter(move |submission| submission.verdict == verdict)
+                .filter_map(|submission| submission.answer.trim().parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| n >= high) {
+            return Some(format!(
+                "`{n}` is not lower than `{high}`, which was too high"
+            ));
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| n <= low) {
+            return Some(format!(
+                "`{n}` is not higher than `{low}`, which was too low"
+            ));
+        }
+        None
+    }
+
+    fn key(day: i32) -> String {
+        format!("day{day:02}")
+    }
+}
+
+/// How many seconds are left before the server accepts another submission from the profile's
+/// account. The server rate-limits the account rather than each part, so this is the longest
+/// wait left from any submission in the log of any year.
+pub fn cooldown(profile: &Profile, now: u64) -> Result<u64> {
+    let mut cooldown = 0;
+    for path in profile.submission_logs()? {
+        cooldown = cooldown.max(Submissions::load_from(path)?.cooldown(now));
+    }
+    Ok(cooldown)
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+/// Formats a number of seconds like the website does, such as `4m 20s`.
+pub fn format_wait(secs: u64) -> String {
+    match (secs / 60, secs % 60) {
+        (0, secs) => format!("{secs}s"),
+        (mins, 0) => format!("{mins}m"),
+        (mins, secs) => format!("{mins}m {secs}s"),
+    }
+}
+
+/// Reads the verdict and cooldown from the page returned after submitting an answer.
+pub fn parse_response(body: &str) -> Option<(Verdict, u64)> {
+    if body.contains("That's the right answer") {
+        Some((Verdict::Correct, 0))
+    } else if body.contains("Did you already complete it") {
+        Some((Verdict::AlreadyCompleted, 0))
+    } else if let Some(wait) = body
+        .split_once("You gave an answer too recently")
+        .and_then(|(_, rest)| rest.split_once("You have "))
+        .and_then(|(_, rest)| rest.split_once(" left to wait"))
+    {
+        Some((Verdict::RateLimited, parse_wait(wait.0)?))
+    } else if body.contains("That's not the right answer") {
+        let verdict = if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        let wait = body
+            .split_once("lease wait ")
+            .and_then(|(_, rest)| rest.split_once(" before trying again"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .unwrap_or(60);
+        Some((verdict, wait))
+    } else {
+        None
+    }
+}
+
+/// Parses waits such as `4m 20s`, `35s`, `one minute` and `5 minutes` into seconds.
+fn parse_wait(wait: &str) -> Option<u64> {
+    match wait.trim() {
+        "one minute" => return Some(60),
+        wait if wait.ends_with(" minutes") => {
+            return wait
+                .trim_end_matches(" minutes")
+                .parse::<u64>()
+                .ok()
+                .map(|mins| mins * 60)
+        }
+        _ => {}
+    }
+
+    wait.split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn submission(answer: &str, verdict: Verdict, submitted_at: u64, wait: u64) -> Submission {
+        Submission {
+            answer: answer.to_owned(),
+            verdict,
+            submitted_at,
+            wait,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = TempDir::new("submissions");
+        let path = dir.join("submissions/2023.toml");
+
+        let mut submissions = Submissions::load_from(&path).unwrap();
+        assert!(submissions.get(1, 1).is_empty());
+
+        submissions.push(1, 1, submission("54572", Verdict::TooLow, 1701406800, 60));
+        submissions.push(1, 1, submission("54573", Verdict::Correct, 1701406900, 0));
+        submissions.save().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "\
+[[day01.part1]]
+answer = \"54572\"
+verdict = \"too_low\"
+submitted_at = 1701406800
+wait = 60
+
+[[day01.part1]]
+answer = \"54573\"
+verdict = \"correct\"
+submitted_at = 1701406900
+"
+        );
+
+        let loaded = Submissions::load_from(&path).unwrap();
+        assert_eq!(loaded, submissions);
+        assert_eq!(loaded.accepted(1, 1), Some("54573"));
+        assert_eq!(loaded.accepted(1, 2), None);
+    }
+
+    #[test]
+    fn safeguards() {
+        let mut submissions = Submissions::default();
+        submissions.push(5, 2, submission("100", Verdict::TooHigh, 1000, 60));
+        submissions.push(5, 2, submission("10", Verdict::TooLow, 1100, 60));
+        submissions.push(5, 2, submission("42", Verdict::Wrong, 1200, 300));
+
+        assert_eq!(submissions.cooldown(1300), 200);
+        assert_eq!(submissions.cooldown(1600), 0);
+        submissions.push(7, 1, submission("3", Verdict::Wrong, 1590, 60));
+        assert_eq!(submissions.cooldown(1600), 50);
+
+        let rule_out = |n: i128| submissions.rule_out(5, 2, &Answer::Integer(n));
+        assert_eq!(
+            rule_out(42).as_deref(),
+            Some("`42` was already submitted and was wrong")
+        );
+        assert!(rule_out(100).unwrap().contains("too high"));
+        assert!(rule_out(5).unwrap().contains("too low"));
+        assert_eq!(rule_out(50), None);
+        assert_eq!(submissions.rule_out(5, 2, &Answer::from("abc")), None);
+    }
+
+    #[test]
+    fn responses() {
+        assert_eq!(
+            parse_response("<p>That's the right answer!  You are one gold star closer"),
+            Some((Verdict::Correct, 0))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>That's not the right answer; your answer is too high.  If you're stuck, make \
+                 sure you're using the full input data. (You guessed <span>100</span>.)  Please \
+                 wait one minute before trying again.</p>"
+            ),
+            Some((Verdict::TooHigh, 60))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>That's not the right answer.  please wait 5 minutes before trying again.</p>"
+            ),
+            Some((Verdict::Wrong, 300))
+        );
+        assert_eq!(
+            parse_response(
+                "<p>You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 4m 20s left to wait."
+            ),
+            Some((Verdict::RateLimited, 260))
+        );
+        assert_eq!(
+            parse_response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some((Verdict::AlreadyCompleted, 0))
+        );
+        assert_eq!(parse_response("<html>"), None);
+        assert_eq!(format_wait(260), "4m 20s");
+        assert_eq!(format_wait(35), "35s");
+    }
+}
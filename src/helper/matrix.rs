@@ -156,6 +156,9 @@ impl<T> Matrix<T> {
     ///
     /// Caller must ensure that there is enough capacity for the new row.
     /// `matrix.row_capacity() >= 1`
+    ///
+    /// With the `safe` feature, both are checked and this panics if either does not hold.
+    #[track_caller]
     pub unsafe fn push_unchecked(&mut self, row: impl AsRef<[T]>)
     where
         T: Clone,
@@ -166,6 +169,23 @@ impl<T> Matrix<T> {
             self.width = NonZeroUsize::new(row.len());
         }
 
+        #[cfg(feature = "safe")]
+        {
+            assert!(
+                row.len() == self.width(),
+                "Tried to push row of length {} to Matrix of width {}.",
+                row.len(),
+                self.width()
+            );
+            assert!(
+                self.inner.capacity() - self.inner.len() >= row.len(),
+                "Tried to push row of length {} to Matrix with capacity for {} more elements.",
+                row.len(),
+                self.inner.capacity() - self.inner.len()
+            );
+            self.inner.extend_from_slice(row);
+        }
+        #[cfg(not(feature = "safe"))]
         self.inner.extend_from_slice_unchecked(row);
     }
 
@@ -1097,4 +1117,23 @@ mod tests {
         *ele0.1 = 2;
         matrix[0][0] = 3;
     }
+
+    #[test]
+    fn push_unchecked() {
+        let mut matrix = Matrix::new();
+        matrix.push([1, 2]);
+        matrix.reserve(1);
+        unsafe { matrix.push_unchecked([3, 4]) };
+        assert_eq!(matrix.into_vec(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(feature = "safe")]
+    #[should_panic(expected = "Tried to push row of length 3 to Matrix of width 2.")]
+    fn push_unchecked_checked() {
+        let mut matrix = Matrix::new();
+        matrix.push([1, 2]);
+        matrix.reserve(2);
+        unsafe { matrix.push_unchecked([3, 4, 5]) };
+    }
 }
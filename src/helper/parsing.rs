@@ -78,6 +78,9 @@ pub trait BytesAsNumber {
     fn as_num<T: PrimInt + 'static>(&self) -> T;
     fn as_signed_num<T: PrimInt + Signed + 'static>(&self) -> T;
     fn as_num_checked<T: PrimInt + 'static>(&self) -> T;
+    /// The number made of all of the bytes, or `None` if one is not a digit, there are none, or
+    /// it does not fit in `T`.
+    fn try_as_num<T: PrimInt + 'static>(&self) -> Option<T>;
     fn as_signed_num_checked<T: PrimInt + Signed + 'static>(&self) -> T;
     fn as_nums<T: PrimInt + 'static>(&self) -> SeparatedNumbers<T>;
     fn as_signed_nums<T: PrimInt + Signed + 'static>(&self) -> SeparatedSignedNumbers<T>;
@@ -117,6 +120,17 @@ impl BytesAsNumber for [u8] {
         out
     }
 
+    fn try_as_num<T: PrimInt + 'static>(&self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.iter().try_fold(T::zero(), |out, &b| {
+            let digit = b.checked_sub(b'0').filter(|&digit| digit < 10)?;
+            out.checked_mul(&T::from(10)?)?
+                .checked_add(&T::from(digit)?)
+        })
+    }
+
     fn as_signed_num_checked<T: PrimInt + Signed + 'static>(&self) -> T {
         let mut out = T::zero();
         if self[0] == b'-' {
@@ -169,7 +183,7 @@ impl StripPrefixUnchecked<u8> for [u8] {
         prefix: &P,
     ) -> Option<&[u8]> {
         let prefix = prefix.as_slice();
-        let (head, tail) = self.split_at_unchecked(prefix.len());
+        let (head, tail) = split_at(self, prefix.len());
         if head == prefix {
             return Some(tail);
         }
@@ -193,8 +207,12 @@ impl PartialConsume<u8> for [u8] {
 
     #[inline]
     fn skip_n(self: &mut &Self, n: usize) -> &[u8] {
-        let (l, r) = unsafe { self.split_at_unchecked(n.min(self.len().saturating_sub(1))) };
-        *self = unsafe { r.get_unchecked(1..) };
+        // There is no separator to skip past the end of an empty slice
+        if self.is_empty() {
+            return &[];
+        }
+        let (l, r) = unsafe { split_at(self, n.min(self.len() - 1)) };
+        *self = unsafe { skip_first(r) };
         l
     }
 
@@ -203,8 +221,8 @@ impl PartialConsume<u8> for [u8] {
         let idx = self.find_byte(unit);
         let ret = match idx {
             Some(idx) => {
-                let (l, r) = unsafe { self.split_at_unchecked(idx) };
-                *self = unsafe { r.get_unchecked(1..) };
+                let (l, r) = unsafe { split_at(self, idx) };
+                *self = unsafe { skip_first(r) };
                 l
             }
             None => {
@@ -222,8 +240,8 @@ impl PartialConsume<u8> for [u8] {
         let idx = self.find(group.as_ref());
         let ret = match idx {
             Some(idx) => {
-                let (l, r) = unsafe { self.split_at_unchecked(idx) };
-                *self = unsafe { r.get_unchecked(1..) };
+                let (l, r) = unsafe { split_at(self, idx) };
+                *self = unsafe { skip_first(r) };
                 l
             }
             None => {
@@ -237,6 +255,44 @@ impl PartialConsume<u8> for [u8] {
     }
 }
 
+/// `slice.split_at_unchecked(mid)`, which is bounds-checked with the `safe` feature.
+///
+/// # Safety
+///
+/// Caller must ensure that `mid <= slice.len()`
+#[inline(always)]
+#[track_caller]
+unsafe fn split_at<T>(slice: &[T], mid: usize) -> (&[T], &[T]) {
+    #[cfg(feature = "safe")]
+    {
+        assert!(
+            mid <= slice.len(),
+            "tried to split a slice of length {} at {mid}",
+            slice.len()
+        );
+        slice.split_at(mid)
+    }
+    #[cfg(not(feature = "safe"))]
+    slice.split_at_unchecked(mid)
+}
+
+/// `slice.get_unchecked(1..)`, which is bounds-checked with the `safe` feature.
+///
+/// # Safety
+///
+/// Caller must ensure that `slice` is not empty
+#[inline(always)]
+#[track_caller]
+unsafe fn skip_first<T>(slice: &[T]) -> &[T] {
+    #[cfg(feature = "safe")]
+    {
+        assert!(!slice.is_empty(), "tried to skip past the end of a slice");
+        &slice[1..]
+    }
+    #[cfg(not(feature = "safe"))]
+    slice.get_unchecked(1..)
+}
+
 pub struct SeparatedNumbers<'a, Output> {
     slice: &'a [u8],
     _output: PhantomData<Output>,
@@ -323,7 +379,7 @@ where
 }
 
 mod tests {
-    use super::{BytesAsNumber, IntoColumns};
+    use super::{BytesAsNumber, IntoColumns, PartialConsume, StripPrefixUnchecked};
 
     #[test]
     pub fn into_columns() {
@@ -351,5 +407,37 @@ mod tests {
     #[test]
     pub fn ascii_as_num() {
         assert_eq!(b"123".as_num::<usize>(), 123);
+        assert_eq!(b"255".try_as_num::<u8>(), Some(255));
+        assert_eq!(b"256".try_as_num::<u8>(), None);
+        assert_eq!(b"1 2".try_as_num::<u8>(), None);
+        assert_eq!(b"".try_as_num::<u8>(), None);
+    }
+
+    #[test]
+    pub fn partial_consume() {
+        let mut slice: &[u8] = b"red, 2";
+        assert_eq!(slice.skip_n(3), b"red");
+        assert_eq!(slice.next(), Some(b' '));
+        assert_eq!(slice.skip_n(5), b"");
+        assert_eq!(slice.skip_n(1), b"");
+        assert_eq!(slice.next(), None);
+    }
+
+    #[test]
+    pub fn strip_prefix_unchecked() {
+        let slice: &[u8] = b"Game 1";
+        assert_eq!(
+            unsafe { slice.strip_prefix_unchecked(b"Game ") },
+            Some(&b"1"[..])
+        );
+        assert_eq!(unsafe { slice.strip_prefix_unchecked(b"Card ") }, None);
+    }
+
+    #[test]
+    #[cfg(feature = "safe")]
+    #[should_panic(expected = "tried to split a slice of length 4 at 5")]
+    pub fn strip_prefix_unchecked_checked() {
+        let slice: &[u8] = b"Game";
+        unsafe { slice.strip_prefix_unchecked(b"Game ") };
     }
 }
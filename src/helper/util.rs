@@ -67,44 +67,36 @@ pub fn hash_ascii_digit_pair(digits: [u8; 2]) -> u8 {
     (((n * 0x10a) >> 8) as u8) & 0x7f
 }
 
+/// `hash_ascii_digit_pair` of each of the four pairs of digits in `digits`, which are separated
+/// by a single byte, such as in `b"88 52 87  9"`.
 #[inline(always)]
 pub fn hash_4_separated_ascii_digit_pairs(digits: [u8; 11]) -> [u8; 4] {
-    use std::arch::is_x86_feature_detected;
-    use std::arch::x86_64::*;
-
-    #[target_feature(enable = "sse2")]
-    unsafe fn inner(digits: [u8; 11]) -> [u8; 4] {
-        let init = _mm_set_epi32(
-            i16::from_le_bytes([digits[9], digits[10]]) as i32,
-            i16::from_le_bytes([digits[6], digits[7]]) as i32,
-            i16::from_le_bytes([digits[3], digits[4]]) as i32,
-            i16::from_le_bytes([digits[0], digits[1]]) as i32,
-        );
-
-        let mul = _mm_mullo_epi32(init, _mm_set1_epi32(0x10a));
-        let shr = _mm_srai_epi32(mul, 8);
-        let and = _mm_and_si128(shr, _mm_set1_epi32(0x7f));
-        let shuffled = _mm_shuffle_epi8(
-            and,
-            _mm_set_epi8(-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 12, 8, 4, 0),
-        );
-
-        let mut out = [0; 4];
-        _mm_storeu_epi8(out.as_mut_ptr(), shuffled);
-
-        std::mem::transmute(out)
+    // The hash fits in 16-bit lanes, which every SIMD extension can multiply, so there is no wider
+    // instruction set worth detecting at runtime. Without SIMD, the lanes would only be emulated
+    if cfg!(any(
+        target_feature = "sse2",
+        target_feature = "neon",
+        target_feature = "simd128"
+    )) {
+        hash_4_separated_ascii_digit_pairs_simd(digits)
+    } else {
+        hash_4_separated_ascii_digit_pairs_scalar(digits)
     }
+}
 
-    // if is_x86_feature_detected!("sse") && cfg!(target_feature = "sse2") {
-    // unsafe { inner(digits) }
-    // } else {
-    [
-        hash_ascii_digit_pair([digits[0], digits[1]]),
-        hash_ascii_digit_pair([digits[3], digits[4]]),
-        hash_ascii_digit_pair([digits[6], digits[7]]),
-        hash_ascii_digit_pair([digits[9], digits[10]]),
-    ]
-    // }
+#[inline(always)]
+pub fn hash_4_separated_ascii_digit_pairs_simd(digits: [u8; 11]) -> [u8; 4] {
+    let pairs = u16x4::from_array(std::array::from_fn(|i| {
+        u16::from_le_bytes([digits[i * 3], digits[i * 3 + 1]])
+    }));
+    // Only bits 8 to 14 of the product are kept, so it can wrap at 16 bits
+    let hashes = ((pairs * u16x4::splat(0x10a)) >> u16x4::splat(8)) & u16x4::splat(0x7f);
+    hashes.cast::<u8>().to_array()
+}
+
+#[inline(always)]
+pub fn hash_4_separated_ascii_digit_pairs_scalar(digits: [u8; 11]) -> [u8; 4] {
+    std::array::from_fn(|i| hash_ascii_digit_pair([digits[i * 3], digits[i * 3 + 1]]))
 }
 
 pub fn binary_search_by<T>(mut low: T, mut high: T, pred: impl Fn(T) -> Ordering) -> Option<T>
@@ -185,15 +177,20 @@ impl Drop for Timer {
 /// Taken from nils https://github.com/Nilstrieb/advent-of-code/blob/45b91e97187f9d59b068c9803b2756239a41a397/helper/src/ext.rs
 /// i will not use itertools i will not use itertools i will not use itertools i will not use itertools
 pub trait CollectToArray: Iterator {
-    /// Collect an iterator into a new array.
+    /// Collect an iterator into a new array, or `None` if it has fewer than `N` items.
     /// If `next` panics, collected items are leaked. Too bad!
     fn collect_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
-        // SAFETY: Uninit is valid for MaybeUninit
-        let mut array: [MaybeUninit<Self::Item>; N] =
-            unsafe { MaybeUninit::uninit().assume_init() };
+        let mut array: [MaybeUninit<Self::Item>; N] = [const { MaybeUninit::uninit() }; N];
 
-        for a in &mut array {
-            a.write(self.next()?);
+        for i in 0..N {
+            let Some(elem) = self.next() else {
+                for elem in &mut array[..i] {
+                    // SAFETY: The first `i` elements have been initialized
+                    unsafe { elem.assume_init_drop() };
+                }
+                return None;
+            };
+            array[i].write(elem);
         }
 
         // SAFETY: All elements have been initialized
@@ -217,17 +214,18 @@ pub trait CollectToArray: Iterator {
         array
     }
 
-    /// Collect an iterator into an existing array.
-    fn collect_into_array<const N: usize>(&mut self, array: &mut [Self::Item; N])
+    /// Collect an iterator into an existing array, returning how many elements were written.
+    fn collect_into_array<const N: usize>(&mut self, array: &mut [Self::Item; N]) -> usize
     where
         Self::Item: Default + Copy,
     {
-        for a in array {
+        for (i, a) in array.iter_mut().enumerate() {
             let Some(elem) = self.next() else {
-                break;
+                return i;
             };
             *a = elem;
         }
+        N
     }
 }
 
@@ -263,8 +261,48 @@ mod tests {
         let input = *b"88 52 87  9";
         let input_two = [*b"88", *b"52", *b"87", *b" 9"];
         let hash = hash_4_separated_ascii_digit_pairs(input);
-        for (input, hash) in input_two.into_iter().zip(hash.into_iter()) {
+        for (input, hash) in input_two.into_iter().zip(hash) {
             assert_eq!(hash_ascii_digit_pair(input), hash);
         }
+        assert_eq!(hash_4_separated_ascii_digit_pairs_simd(input), hash);
+        assert_eq!(hash_4_separated_ascii_digit_pairs_scalar(input), hash);
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    fn all_digit_pairs() {
+        // Every pair of bytes, not just digits, so that the paths agree on any input
+        for n in (0..=u16::MAX).step_by(4) {
+            let mut digits = [b' '; 11];
+            for i in 0..4 {
+                digits[i * 3..i * 3 + 2].copy_from_slice(&(n + i as u16).to_le_bytes());
+            }
+            let expected =
+                array::from_fn(|i| hash_ascii_digit_pair([digits[i * 3], digits[i * 3 + 1]]));
+            assert_eq!(hash_4_separated_ascii_digit_pairs(digits), expected);
+            assert_eq!(hash_4_separated_ascii_digit_pairs_simd(digits), expected);
+            assert_eq!(hash_4_separated_ascii_digit_pairs_scalar(digits), expected);
+        }
+    }
+
+    #[test]
+    fn collect_arrays() {
+        let words = || ["a", "b"].into_iter().map(str::to_owned);
+        assert_eq!(
+            words().collect_array::<2>(),
+            Some(["a".to_owned(), "b".to_owned()])
+        );
+        // The items taken from a short iterator are dropped rather than leaked, which Miri checks
+        assert_eq!(words().collect_array::<3>(), None);
+        assert_eq!((1..).collect_array::<3>(), Some([1, 2, 3]));
+    }
+
+    #[test]
+    fn collect_into_arrays() {
+        let mut array = [0; 4];
+        assert_eq!([1, 2].into_iter().collect_into_array(&mut array), 2);
+        assert_eq!(array, [1, 2, 0, 0]);
+        assert_eq!((1..).collect_into_array(&mut array), 4);
+        assert_eq!(array, [1, 2, 3, 4]);
     }
 }
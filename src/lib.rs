@@ -1,4 +1,3 @@
-#![feature(stdsimd)]
 #![feature(array_chunks)]
 #![feature(iter_array_chunks)]
 #![feature(array_windows)]
@@ -15,7 +14,15 @@
 #![feature(maybe_uninit_uninit_array)]
 #![feature(maybe_uninit_array_assume_init)]
 #![feature(int_roundings)]
+#![feature(inline_const)]
 #![allow(unused)]
 
+pub mod api;
+pub mod cli;
 pub mod helper;
 pub mod solutions;
+#[cfg(test)]
+mod test_support;
+
+pub use api::{run, run_variant, RunError};
+pub use solutions::{solutions, Answer, SolveError};
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// What a part calculated, which solutions return as anything that converts into it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+    /// Letters drawn over several lines, which have to be read off before submitting them.
+    Art(String),
+    /// Parts that print their results instead of returning them.
+    None,
+}
+
+impl Answer {
+    /// Whether this answer is the same as one recorded as `expected`, comparing integers
+    /// numerically so that formatting such as leading zeroes does not matter.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Integer(n) => expected.trim().parse::<i128>() == Ok(*n),
+            Self::String(s) => s == expected.trim(),
+            Self::Art(art) => art
+                .lines()
+                .map(str::trim_end)
+                .eq(expected.trim_matches('\n').lines().map(str::trim_end)),
+            Self::None => false,
+        }
+    }
+
+    /// The answer as it would be typed into the website, if it can be submitted as is.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Self::Integer(_) | Self::String(_) => Some(self.to_string()),
+            Self::Art(_) | Self::None => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::String(s) | Self::Art(s) => f.write_str(s),
+            Self::None => Ok(()),
+        }
+    }
+}
+
+macro_rules! from_integers {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Self::Integer(n.into())
+                }
+            }
+        )*
+    };
+}
+
+from_integers!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Self::Integer(n),
+            Err(_) => Self::String(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::String(s.to_owned())
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Self::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::from(42u8), Answer::from(42usize));
+        assert_eq!(Answer::from(-3i64), Answer::Integer(-3));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("CMZ"), Answer::String("CMZ".to_owned()));
+        assert_eq!(Answer::from(()), Answer::None);
+    }
+
+    #[test]
+    fn matches() {
+        assert!(Answer::Integer(54573).matches("54573"));
+        assert!(Answer::Integer(7).matches("007"));
+        assert!(!Answer::Integer(7).matches("seven"));
+        assert!(Answer::from("CMZ").matches("CMZ\n"));
+        assert!(!Answer::from("CMZ").matches("cmz"));
+        assert!(Answer::Art("##  \n# # ".to_owned()).matches("\n##\n# #\n"));
+        assert!(!Answer::None.matches(""));
+    }
+
+    #[test]
+    fn submission() {
+        assert_eq!(Answer::Integer(-1).submission().as_deref(), Some("-1"));
+        assert_eq!(Answer::Art("#".to_owned()).submission(), None);
+        assert_eq!(Answer::None.submission(), None);
+    }
+}
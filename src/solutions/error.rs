@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Why a part could not solve its input, such as a line it could not parse, with where in the
+/// input the problem is when the part knows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolveError {
+    message: String,
+    location: Option<Location>,
+}
+
+/// A position in a part's input, counting lines and bytes within them from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// The position of the byte at `offset`, which may be the end of the input.
+    pub fn of_offset(input: &[u8], offset: usize) -> Self {
+        let before = &input[..offset.min(input.len())];
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |idx| idx + 1);
+        Self {
+            line: before.iter().filter(|&&b| b == b'\n').count() + 1,
+            column: before.len() - line_start + 1,
+        }
+    }
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// An error about the byte at `offset` in `input`.
+    pub fn at(input: impl AsRef<[u8]>, offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            location: Some(Location::of_offset(input.as_ref(), offset)),
+            ..Self::new(message)
+        }
+    }
+
+    /// An error about the start of `rest`, a subslice of `input` such as what is left of a line
+    /// being parsed. The location is left out if `rest` is not part of `input`.
+    pub fn at_slice(input: impl AsRef<[u8]>, rest: &[u8], message: impl Into<String>) -> Self {
+        let input = input.as_ref();
+        let offset = (rest.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        if offset > input.len() {
+            return Self::new(message);
+        }
+        Self::at(input, offset, message)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location {
+            Some(Location { line, column }) => {
+                write!(f, "line {line}, column {column}: {}", self.message)
+            }
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locations() {
+        let input = "ab\ncde\n\nf";
+        assert_eq!(
+            Location::of_offset(input.as_bytes(), 0),
+            Location { line: 1, column: 1 }
+        );
+        assert_eq!(
+            Location::of_offset(input.as_bytes(), 5),
+            Location { line: 2, column: 3 }
+        );
+        assert_eq!(
+            Location::of_offset(input.as_bytes(), 7),
+            Location { line: 3, column: 1 }
+        );
+        assert_eq!(
+            Location::of_offset(input.as_bytes(), 100),
+            Location { line: 4, column: 2 }
+        );
+
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            SolveError::at_slice(input, &line.as_bytes()[1..], "expected a digit").to_string(),
+            "line 2, column 2: expected a digit"
+        );
+        assert_eq!(
+            SolveError::at_slice(input, b"elsewhere", "expected a digit").location(),
+            None
+        );
+        assert_eq!(SolveError::new("empty input").to_string(), "empty input");
+    }
+}
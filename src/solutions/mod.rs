@@ -1,96 +1,547 @@
-use std::collections::BTreeMap;
-use std::fmt::Display;
+use std::{
+    borrow::Borrow,
+    collections::BTreeMap,
+    fmt::Display,
+    sync::{Arc, OnceLock},
+};
 
-pub mod year2022;
-pub mod year2023;
+/// Generates a test for each part that checks its answers to the puzzle's worked examples.
+///
+/// ```ignore
+/// examples! {
+///     part1: ["1abc2\npqr3stu8vwx" => 50],
+///     part2: ["two1nine" => 29, "eightwothree" => 83],
+/// }
+/// ```
+///
+/// Days with a parse step start with `parse;`, so that the examples are parsed before being
+/// passed to the parts.
+macro_rules! examples {
+    {parse; $($part:ident: [$($input:expr => $expected:expr),*$(,)?]),*$(,)?} => {
+        #[cfg(test)]
+        mod examples {
+            use std::borrow::Borrow;
+
+            use super::*;
+            use crate::solutions::{Answer, PartOutput};
 
-macro_rules! solutions {
-    {$($years:expr => [$([$($parts:expr),*]),*$(,)?]),*$(,)?} => {
-        pub fn solutions() -> BTreeMap<i32, [[Box<dyn Fn(&str) -> String>; 2]; 25]> {
-            fn translate<D: Display + 'static>(f: fn(&str) -> D) -> Box<dyn Fn(&str) -> String> {
-                Box::new(move |x| f(x).to_string())
-            }
-            let mut sols = BTreeMap::new();
             $(
-                sols.insert($years, [$(
-                    [$(
-                        translate($parts)
-                    ),*]
-                ),*]);
+                #[test]
+                fn $part() {
+                    $(
+                        let input = super::parse($input);
+                        assert_eq!(super::$part(input.borrow()).into_result(), Ok(Answer::from($expected)));
+                    )*
+                }
             )*
-            sols
         }
+    };
+    {$($part:ident: [$($input:expr => $expected:expr),*$(,)?]),*$(,)?} => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+            use crate::solutions::{Answer, PartOutput};
 
-        pub fn stubs() -> BTreeMap<i32, [[Box<dyn Fn(&str)>; 2]; 25]> {
-            fn translate<D: Display + 'static>(f: fn(&str) -> D) -> Box<dyn Fn(&str)> {
-                Box::new(move |x| { ::std::hint::black_box(f(x)); })
-            }
-            let mut sols = BTreeMap::new();
             $(
-                sols.insert($years, [$(
-                    [$(
-                        translate($parts)
-                    ),*]
-                ),*]);
+                #[test]
+                fn $part() {
+                    $(
+                        assert_eq!(super::$part($input).into_result(), Ok(Answer::from($expected)));
+                    )*
+                }
             )*
-            sols
         }
     };
 }
 
-solutions! {
-    2022 => [
-        [year2022::day01::part1, year2022::day01::part2],
-        [year2022::day02::part1, year2022::day02::part2],
-        [year2022::day03::part1, year2022::day03::part2],
-        [year2022::day04::part1, year2022::day04::part2],
-        [year2022::day05::part1, year2022::day05::part2],
-        [year2022::day06::part1, year2022::day06::part2],
-        [year2022::day07::part1, year2022::day07::part2],
-        [year2022::day08::part1, year2022::day08::part2],
-        [year2022::day09::part1, year2022::day09::part2],
-        [year2022::day10::part1, year2022::day10::part2],
-        [year2022::day11::part1, year2022::day11::part2],
-        [year2022::day12::part1, year2022::day12::part2],
-        [year2022::day13::part1, year2022::day13::part2],
-        [year2022::day14::part1, year2022::day14::part2],
-        [year2022::day15::part1, year2022::day15::part2],
-        [year2022::day16::part1, year2022::day16::part2],
-        [year2022::day17::part1, year2022::day17::part2],
-        [year2022::day18::part1, year2022::day18::part2],
-        [year2022::day19::part1, year2022::day19::part2],
-        [year2022::day20::part1, year2022::day20::part2],
-        [year2022::day21::part1, year2022::day21::part2],
-        [year2022::day22::part1, year2022::day22::part2],
-        [year2022::day23::part1, year2022::day23::part2],
-        [year2022::day24::part1, year2022::day24::part2],
-        [year2022::day25::part1, year2022::day25::part2],
-    ],
-    2023 => [
-        [year2023::day01::part1, year2023::day01::part2],
-        [year2023::day02::part1, year2023::day02::part2],
-        [year2023::day03::part1, year2023::day03::part2],
-        [year2023::day04::part1, year2023::day04::part2],
-        [year2023::day05::part1, year2023::day05::part2],
-        [year2023::day06::part1, year2023::day06::part2],
-        [year2023::day07::part1, year2023::day07::part2],
-        [year2023::day08::part1, year2023::day08::part2],
-        [year2023::day09::part1, year2023::day09::part2],
-        [year2023::day10::part1, year2023::day10::part2],
-        [year2023::day11::part1, year2023::day11::part2],
-        [year2023::day12::part1, year2023::day12::part2],
-        [year2023::day13::part1, year2023::day13::part2],
-        [year2023::day14::part1, year2023::day14::part2],
-        [year2023::day15::part1, year2023::day15::part2],
-        [year2023::day16::part1, year2023::day16::part2],
-        [year2023::day17::part1, year2023::day17::part2],
-        [year2023::day18::part1, year2023::day18::part2],
-        [year2023::day19::part1, year2023::day19::part2],
-        [year2023::day20::part1, year2023::day20::part2],
-        [year2023::day21::part1, year2023::day21::part2],
-        [year2023::day22::part1, year2023::day22::part2],
-        [year2023::day23::part1, year2023::day23::part2],
-        [year2023::day24::part1, year2023::day24::part2],
-        [year2023::day25::part1, year2023::day25::part2],
-    ],
+mod answer;
+mod error;
+
+pub use answer::Answer;
+pub use error::{Location, SolveError};
+
+/// Returned by parts that have not been solved yet, so that the registry can tell them apart
+/// without running them.
+pub struct Unimplemented;
+
+/// What parts can return: anything that converts into an `Answer`, or a `Result` of one for
+/// parts that check their input rather than assuming it is well formed.
+pub trait PartOutput {
+    const IMPLEMENTED: bool = true;
+
+    fn into_result(self) -> Result<Answer, SolveError>;
+}
+
+impl<A: Into<Answer>> PartOutput for A {
+    fn into_result(self) -> Result<Answer, SolveError> {
+        Ok(self.into())
+    }
+}
+
+impl<A: Into<Answer>> PartOutput for Result<A, SolveError> {
+    fn into_result(self) -> Result<Answer, SolveError> {
+        self.map(Into::into)
+    }
+}
+
+/// The error a part rejected its input with, for testing malformed inputs.
+#[cfg(test)]
+#[track_caller]
+pub(crate) fn rejection(output: impl PartOutput) -> String {
+    output.into_result().unwrap_err().to_string()
+}
+
+impl PartOutput for Unimplemented {
+    const IMPLEMENTED: bool = false;
+
+    fn into_result(self) -> Result<Answer, SolveError> {
+        Ok(Answer::None)
+    }
+}
+
+/// A day's solutions, with an optional parse step whose result is shared by both parts.
+pub struct Day {
+    parse: Box<dyn for<'a> Fn(&'a str) -> Parsed<'a> + Send + Sync>,
+    variants: [Vec<(&'static str, bool)>; 2],
+    has_parse: bool,
+}
+
+/// A day's input after parsing, which its parts can be run on any number of times.
+pub struct Parsed<'a>(Box<dyn Fn(i32, usize) -> Result<Answer, SolveError> + 'a>);
+
+/// The implementations of a day's parts that all take `&T`: the default `part1` and `part2`,
+/// and any number of named alternatives to them, such as a readable reference solution kept next
+/// to an optimised one. Parts without any implementations, such as day 25's second part, do not
+/// exist.
+pub struct Variants<T: ?Sized> {
+    parts: [Vec<Variant<T>>; 2],
+}
+
+struct Variant<T: ?Sized> {
+    name: &'static str,
+    implemented: bool,
+    solve: Solve<T>,
+}
+
+type Solve<T> = Box<dyn Fn(&T) -> Result<Answer, SolveError> + Send + Sync>;
+
+impl<T: ?Sized + 'static> Variants<T> {
+    /// The name of the variants given to `new`.
+    pub const DEFAULT: &'static str = "default";
+
+    pub fn new<D1: PartOutput + 'static, D2: PartOutput + 'static>(
+        part1: fn(&T) -> D1,
+        part2: fn(&T) -> D2,
+    ) -> Self {
+        Self {
+            parts: [
+                vec![Variant::new(Self::DEFAULT, part1)],
+                vec![Variant::new(Self::DEFAULT, part2)],
+            ],
+        }
+    }
+
+    /// A day with only a first part.
+    pub fn single<D: PartOutput + 'static>(part1: fn(&T) -> D) -> Self {
+        Self {
+            parts: [vec![Variant::new(Self::DEFAULT, part1)], Vec::new()],
+        }
+    }
+
+    /// Adds another implementation of `part` under `name`.
+    pub fn variant<D: PartOutput + 'static>(
+        mut self,
+        part: i32,
+        name: &'static str,
+        solve: fn(&T) -> D,
+    ) -> Self {
+        self.parts[part as usize - 1].push(Variant::new(name, solve));
+        self
+    }
+
+    fn names(&self) -> [Vec<(&'static str, bool)>; 2] {
+        self.parts.each_ref().map(|variants| {
+            variants
+                .iter()
+                .map(|variant| (variant.name, variant.implemented))
+                .collect()
+        })
+    }
+
+    fn solve(&self, part: i32, variant: usize, input: &T) -> Result<Answer, SolveError> {
+        let solve = match part {
+            1 | 2 => &self.parts[part as usize - 1][variant].solve,
+            _ => panic!("part {part} does not exist"),
+        };
+        solve(input)
+    }
+}
+
+impl<T: ?Sized + 'static> Variant<T> {
+    fn new<D: PartOutput + 'static>(name: &'static str, solve: fn(&T) -> D) -> Self {
+        Self {
+            name,
+            implemented: D::IMPLEMENTED,
+            solve: Box::new(move |input| solve(input).into_result()),
+        }
+    }
+}
+
+impl Day {
+    /// A day whose parts take the input as is.
+    pub fn new<D1: PartOutput + 'static, D2: PartOutput + 'static>(
+        part1: fn(&str) -> D1,
+        part2: fn(&str) -> D2,
+    ) -> Self {
+        Self::from_variants(Variants::new(part1, part2))
+    }
+
+    /// A day that parses its input once and passes the result to both parts, which can take it
+    /// as anything it borrows as, such as a slice of a parsed `Vec`.
+    pub fn with_parse<I, T, D1, D2>(
+        parse: fn(&str) -> I,
+        part1: fn(&T) -> D1,
+        part2: fn(&T) -> D2,
+    ) -> Self
+    where
+        I: Borrow<T> + 'static,
+        T: ?Sized + 'static,
+        D1: PartOutput + 'static,
+        D2: PartOutput + 'static,
+    {
+        Self::with_parse_variants(parse, Variants::new(part1, part2))
+    }
+
+    /// Like `new`, for a day with several variants of its parts.
+    pub fn from_variants(variants: Variants<str>) -> Self {
+        let names = variants.names();
+        let variants = Arc::new(variants);
+        Self {
+            parse: Box::new(move |input: &str| {
+                let variants = Arc::clone(&variants);
+                Parsed(Box::new(move |part, variant| {
+                    variants.solve(part, variant, input)
+                }))
+            }),
+            variants: names,
+            has_parse: false,
+        }
+    }
+
+    /// Like `with_parse`, for a day with several variants of its parts.
+    pub fn with_parse_variants<I, T>(parse: fn(&str) -> I, variants: Variants<T>) -> Self
+    where
+        I: Borrow<T> + 'static,
+        T: ?Sized + 'static,
+    {
+        let names = variants.names();
+        let variants = Arc::new(variants);
+        Self {
+            parse: Box::new(move |input: &str| {
+                let input = parse(input);
+                let variants = Arc::clone(&variants);
+                Parsed(Box::new(move |part, variant| {
+                    variants.solve(part, variant, input.borrow())
+                }))
+            }),
+            variants: names,
+            has_parse: true,
+        }
+    }
+
+    /// The parts that the day has, which is only the first one on day 25.
+    pub fn parts(&self) -> impl Iterator<Item = i32> + '_ {
+        (1..=2).filter(|&part| self.has_part(part))
+    }
+
+    pub fn has_part(&self, part: i32) -> bool {
+        !self.part_variants(part).is_empty()
+    }
+
+    /// Whether the default variant of `part` is implemented, which is false for parts that do not
+    /// exist.
+    pub fn is_implemented(&self, part: i32) -> bool {
+        self.is_variant_implemented(part, 0)
+    }
+
+    pub fn is_variant_implemented(&self, part: i32, variant: usize) -> bool {
+        self.part_variants(part)
+            .get(variant)
+            .is_some_and(|&(_, implemented)| implemented)
+    }
+
+    /// The names of a part's variants, starting with the default one.
+    pub fn variants(&self, part: i32) -> impl Iterator<Item = &'static str> + '_ {
+        self.part_variants(part).iter().map(|&(name, _)| name)
+    }
+
+    fn part_variants(&self, part: i32) -> &[(&'static str, bool)] {
+        match part {
+            1 | 2 => &self.variants[part as usize - 1],
+            _ => &[],
+        }
+    }
+
+    /// The index of a part's variant called `name`, to pass to `Parsed::solve_variant`.
+    pub fn variant(&self, part: i32, name: &str) -> Option<usize> {
+        self.variants(part).position(|variant| variant == name)
+    }
+
+    /// Whether the day has a parse step, rather than passing the input to its parts as is.
+    pub fn has_parse(&self) -> bool {
+        self.has_parse
+    }
+
+    pub fn parse<'a>(&self, input: &'a str) -> Parsed<'a> {
+        (self.parse)(input)
+    }
+}
+
+impl Parsed<'_> {
+    /// Runs the default variant of `part`, which fails if the part found the input malformed.
+    pub fn solve(&self, part: i32) -> Result<Answer, SolveError> {
+        self.solve_variant(part, 0)
+    }
+
+    pub fn solve_variant(&self, part: i32, variant: usize) -> Result<Answer, SolveError> {
+        (self.0)(part, variant)
+    }
+}
+
+/// Every registered solution, keyed by year and day. Years, days and parts that have not been
+/// added are missing, rather than registered as not implemented.
+#[derive(Default)]
+pub struct Solutions {
+    days: BTreeMap<(i32, i32), Day>,
+}
+
+/// A year, day or part that has no registered solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoSuchSolution {
+    Year(i32),
+    Day(i32, i32),
+    Part(i32, i32, i32),
+}
+
+impl Display for NoSuchSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Year(year) => write!(f, "no solutions for {year}"),
+            Self::Day(year, day) => write!(f, "no solution for {year} day {day}"),
+            Self::Part(year, day @ 25, part) => {
+                write!(
+                    f,
+                    "{year} day {day} has no part {part}, as it only has one part"
+                )
+            }
+            Self::Part(year, day, part) => {
+                write!(f, "no solution for {year} day {day} part {part}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NoSuchSolution {}
+
+impl Solutions {
+    fn insert(&mut self, year: i32, day: i32, solution: Day) {
+        self.days.insert((year, day), solution);
+    }
+
+    /// The years with at least one registered day.
+    pub fn years(&self) -> Vec<i32> {
+        let mut years = self.days.keys().map(|&(year, _)| year).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    /// The registered days of `year`, in order.
+    pub fn days(&self, year: i32) -> impl Iterator<Item = (i32, &Day)> {
+        self.days
+            .range((year, i32::MIN)..=(year, i32::MAX))
+            .map(|(&(_, day), solution)| (day, solution))
+    }
+
+    pub fn day(&self, year: i32, day: i32) -> Result<&Day, NoSuchSolution> {
+        self.days.get(&(year, day)).ok_or_else(|| {
+            if self.days(year).next().is_none() {
+                NoSuchSolution::Year(year)
+            } else {
+                NoSuchSolution::Day(year, day)
+            }
+        })
+    }
+
+    /// The day that `part` belongs to, if it has that part.
+    pub fn part(&self, year: i32, day: i32, part: i32) -> Result<&Day, NoSuchSolution> {
+        let solution = self.day(year, day)?;
+        if !solution.has_part(part) {
+            return Err(NoSuchSolution::Part(year, day, part));
+        }
+        Ok(solution)
+    }
+}
+
+/// Parts that a day does not export, which take whatever the other part does.
+pub fn unimplemented<T: ?Sized>(_: &T) -> Unimplemented {
+    Unimplemented
+}
+
+/// Every registered solution, which is built the first time it is needed.
+pub fn solutions() -> &'static Solutions {
+    static SOLUTIONS: OnceLock<Solutions> = OnceLock::new();
+    SOLUTIONS.get_or_init(register)
+}
+
+// Declares every `year<year>/day<day>.rs` module and registers it in `register()`, see build.rs
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_parse() {
+        let day = Day::with_parse(
+            |input| {
+                input
+                    .lines()
+                    .map(|line| line.parse().unwrap())
+                    .collect::<Vec<u32>>()
+            },
+            |nums: &[u32]| nums.iter().sum::<u32>(),
+            |_: &[u32]| Unimplemented,
+        );
+        assert!(day.has_parse());
+        assert_eq!(
+            (day.is_implemented(1), day.is_implemented(2)),
+            (true, false)
+        );
+
+        let parsed = day.parse("1\n2\n3");
+        assert_eq!(parsed.solve(1), Ok(Answer::Integer(6)));
+        assert_eq!(parsed.solve(1), Ok(Answer::Integer(6)));
+    }
+
+    #[test]
+    fn registry() {
+        assert_eq!(YEARS, [2022, 2023]);
+        let solutions = solutions();
+        assert_eq!(solutions.years(), YEARS);
+        assert!(solutions.day(2023, 8).unwrap().has_parse());
+        assert!(solutions.part(2023, 1, 1).unwrap().is_implemented(1));
+        assert!(!solutions.part(2023, 25, 1).unwrap().is_implemented(1));
+        assert_eq!(
+            solutions.day(2023, 25).unwrap().parts().collect::<Vec<_>>(),
+            [1]
+        );
+        let day = solutions.day(2023, 5).unwrap();
+        assert_eq!(day.variant(2, "ranges"), Some(1));
+        assert_eq!(day.variants(1).count(), 1);
+        assert_eq!(solutions.days(2022).count(), 25);
+
+        let day = Day::with_parse(|input| input.len(), |len: &usize| *len, unimplemented);
+        assert_eq!(
+            (day.is_implemented(1), day.is_implemented(2)),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn variants() {
+        let day = Day::from_variants(
+            Variants::new(
+                |input: &str| input.len(),
+                |input: &str| input.to_uppercase(),
+            )
+            .variant(1, "words", |input: &str| input.split_whitespace().count())
+            .variant(1, "chars", |input: &str| input.chars().count()),
+        );
+        assert_eq!(
+            day.variants(1).collect::<Vec<_>>(),
+            ["default", "words", "chars"]
+        );
+        assert_eq!(day.variants(2).collect::<Vec<_>>(), ["default"]);
+        assert_eq!(day.variant(1, "chars"), Some(2));
+        assert_eq!(day.variant(2, "chars"), None);
+
+        let parsed = day.parse("héllo");
+        assert_eq!(parsed.solve(1), Ok(Answer::Integer(6)));
+        assert_eq!(parsed.solve_variant(1, 1), Ok(Answer::Integer(1)));
+        assert_eq!(parsed.solve_variant(1, 2), Ok(Answer::Integer(5)));
+
+        let day = Day::from_variants(Variants::new(unimplemented, unimplemented).variant(
+            2,
+            "wip",
+            |_: &str| Unimplemented,
+        ));
+        assert!(!day.is_implemented(2) && !day.is_variant_implemented(2, 1));
+    }
+
+    #[test]
+    fn missing_solutions() {
+        let mut solutions = Solutions::default();
+        solutions.insert(2015, 3, Day::new(|input| input.len(), unimplemented));
+        solutions.insert(2015, 25, Day::from_variants(Variants::single(|_: &str| 1)));
+
+        assert_eq!(
+            solutions.days(2015).map(|(day, _)| day).collect::<Vec<_>>(),
+            [3, 25]
+        );
+        assert!(solutions.part(2015, 3, 2).is_ok());
+        assert_eq!(
+            solutions.day(2015, 4).err(),
+            Some(NoSuchSolution::Day(2015, 4))
+        );
+        assert_eq!(
+            solutions.day(2016, 3).err(),
+            Some(NoSuchSolution::Year(2016))
+        );
+        assert_eq!(
+            solutions.part(2015, 25, 2).err(),
+            Some(NoSuchSolution::Part(2015, 25, 2))
+        );
+        assert_eq!(
+            solutions.part(2015, 3, 3).err(),
+            Some(NoSuchSolution::Part(2015, 3, 3))
+        );
+        assert_eq!(
+            NoSuchSolution::Part(2015, 25, 2).to_string(),
+            "2015 day 25 has no part 2, as it only has one part"
+        );
+
+        let day = solutions.day(2015, 25).unwrap();
+        assert!(!day.has_part(2) && !day.is_implemented(2));
+        assert_eq!(day.variants(2).count(), 0);
+    }
+
+    #[test]
+    fn fallible() {
+        let day = Day::new(
+            |input: &str| {
+                input
+                    .lines()
+                    .map(|line| {
+                        line.parse::<u32>().map_err(|_| {
+                            SolveError::at_slice(input, line.as_bytes(), "expected a number")
+                        })
+                    })
+                    .sum::<Result<u32, _>>()
+            },
+            unimplemented,
+        );
+        assert!(day.is_implemented(1));
+        assert_eq!(day.parse("1\n2").solve(1), Ok(Answer::Integer(3)));
+        assert_eq!(
+            day.parse("1\n2\nthree").solve(1).unwrap_err().to_string(),
+            "line 3, column 1: expected a number"
+        );
+    }
+
+    #[test]
+    fn without_parse() {
+        let day = Day::new(|input| input.len(), |input| input.to_uppercase());
+        assert!(!day.has_parse());
+        assert_eq!(day.parse("abc").solve(2), Ok(Answer::from("ABC")));
+    }
 }
@@ -1,6 +1,8 @@
-use std::{collections::BinaryHeap, fmt::Display};
+use std::collections::BinaryHeap;
 
-pub fn part1(input: &str) -> impl Display {
+use crate::solutions::Answer;
+
+pub fn part1(input: &str) -> impl Into<Answer> {
     input
         .split("\n\n")
         .map(|s| s.lines().map(|s| s.parse::<i32>().unwrap()).sum::<i32>())
@@ -8,10 +10,32 @@ pub fn part1(input: &str) -> impl Display {
         .unwrap()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let mut heap: BinaryHeap<i32> = input
         .split("\n\n")
         .map(|s| s.lines().map(|s| s.parse::<i32>().unwrap()).sum())
         .collect();
     heap.pop().unwrap() + heap.pop().unwrap() + heap.pop().unwrap()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+examples! {
+    part1: [EXAMPLE => 24000],
+    part2: [EXAMPLE => 45000],
+}
@@ -1,7 +1,7 @@
-use std::fmt::Display;
+use crate::solutions::Answer;
 
 #[allow(clippy::identity_op)]
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let mut score = 0;
     input.lines().for_each(|l| {
         l.split_once(' ')
@@ -31,7 +31,7 @@ pub fn part1(input: &str) -> impl Display {
     score
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let mut score = 0;
     input.lines().for_each(|l| {
         l.split_once(' ')
@@ -60,3 +60,14 @@ pub fn part2(input: &str) -> impl Display {
     });
     score
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+A Y
+B X
+C Z";
+
+examples! {
+    part1: [EXAMPLE => 15],
+    part2: [EXAMPLE => 12],
+}
@@ -1,6 +1,6 @@
-use std::fmt::Display;
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     input
         .lines()
         .map(|l| {
@@ -22,7 +22,7 @@ pub fn part1(input: &str) -> impl Display {
         .sum::<isize>()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     input
         .lines()
         .array_chunks::<3>()
@@ -42,3 +42,17 @@ pub fn part2(input: &str) -> impl Display {
         })
         .sum::<isize>()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+examples! {
+    part1: [EXAMPLE => 157],
+    part2: [EXAMPLE => 70],
+}
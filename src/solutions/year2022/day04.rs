@@ -1,6 +1,8 @@
-use std::{collections::BTreeSet, fmt::Display};
+use std::collections::BTreeSet;
 
-pub fn part1(input: &str) -> impl Display {
+use crate::solutions::Answer;
+
+pub fn part1(input: &str) -> impl Into<Answer> {
     input
         .lines()
         .map(|l| {
@@ -14,7 +16,7 @@ pub fn part1(input: &str) -> impl Display {
         .sum::<u64>()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     input
         .lines()
         .map(|l| {
@@ -27,3 +29,17 @@ pub fn part2(input: &str) -> impl Display {
         })
         .sum::<u64>()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+examples! {
+    part1: [EXAMPLE => 2],
+    part2: [EXAMPLE => 4],
+}
@@ -1,8 +1,7 @@
-use std::fmt::Display;
-
 use crate::helper::parsing::IntoColumns;
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let mut blocks = input
         .lines()
         .take_while(|l| !l.is_empty())
@@ -10,7 +9,7 @@ pub fn part1(input: &str) -> impl Display {
         .filter(|c| !c.chars().all(|c| matches!(c, ' ' | '[' | ']')))
         .map(|s| s.trim_start().chars().collect::<Vec<_>>())
         .collect::<Vec<_>>();
-    for insn in input.lines().skip(blocks.len() + 1) {
+    for insn in input.lines().skip_while(|l| !l.is_empty()).skip(1) {
         let split = insn.split(' ').collect::<Vec<_>>();
         let num: usize = split[1].parse().unwrap();
         let fr: usize = split[3].parse::<usize>().unwrap() - 1;
@@ -26,7 +25,7 @@ pub fn part1(input: &str) -> impl Display {
         .collect::<String>()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let mut blocks = input
         .lines()
         .take_while(|l| !l.is_empty())
@@ -34,7 +33,7 @@ pub fn part2(input: &str) -> impl Display {
         .filter(|c| !c.chars().all(|c| matches!(c, ' ' | '[' | ']')))
         .map(|s| s.trim_start().chars().collect::<Vec<_>>())
         .collect::<Vec<_>>();
-    for insn in input.lines().skip(blocks.len() + 1) {
+    for insn in input.lines().skip_while(|l| !l.is_empty()).skip(1) {
         let split = insn.split(' ').collect::<Vec<_>>();
         let num: usize = split[1].parse().unwrap();
         let fr: usize = split[3].parse::<usize>().unwrap() - 1;
@@ -52,3 +51,22 @@ pub fn part2(input: &str) -> impl Display {
         .map(|v| v.first().cloned().unwrap_or(' '))
         .collect::<String>()
 }
+
+// The drawing's lines are padded to the same width, as `into_columns` stops at the shortest one
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "    [D]    \n",
+    "[N] [C]    \n",
+    "[Z] [M] [P]\n",
+    " 1   2   3 \n",
+    "\n",
+    "move 1 from 2 to 1\n",
+    "move 3 from 1 to 3\n",
+    "move 2 from 2 to 1\n",
+    "move 1 from 1 to 2",
+);
+
+examples! {
+    part1: [EXAMPLE => "CMZ"],
+    part2: [EXAMPLE => "MCD"],
+}
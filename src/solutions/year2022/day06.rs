@@ -1,5 +1,10 @@
-use std::{fmt::Display, ops::Range};
+use std::ops::Range;
 
+use crate::solutions::{Answer, SolveError};
+
+/// # Safety
+///
+/// Every byte of `arr` must be a lowercase ASCII letter.
 unsafe fn unique<const N: usize>(arr: &[u8; N]) -> bool {
     let mut found = 0u32;
     let mut i = 0;
@@ -14,19 +19,51 @@ unsafe fn unique<const N: usize>(arr: &[u8; N]) -> bool {
     true
 }
 
-fn solve<const N: usize>(input: &str) -> impl Display {
+fn solve<const N: usize>(input: &str) -> Result<impl Into<Answer>, SolveError> {
+    let input = input.as_bytes();
+    if let Some(idx) = input.iter().position(|b| !b.is_ascii_lowercase()) {
+        return Err(SolveError::at(input, idx, "expected a lowercase letter"));
+    }
+
     input
-        .as_bytes()
         .array_windows::<N>()
+        // SAFETY: Every byte was checked to be a lowercase letter above
         .position(|w| unsafe { unique(w) })
-        .unwrap()
-        + N
+        .map(|idx| idx + N)
+        .ok_or_else(|| SolveError::new(format!("no {N} letters in a row are all different")))
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> Result<impl Into<Answer>, SolveError> {
     solve::<4>(input)
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> Result<impl Into<Answer>, SolveError> {
     solve::<14>(input)
 }
+
+examples! {
+    part1: ["mjqjpqmgbljsphdztnvjfqwrcgsmlb" => 7, "bvwbjplbgvbhsrlpgdmjqwftvncz" => 5],
+    part2: ["mjqjpqmgbljsphdztnvjfqwrcgsmlb" => 19, "bvwbjplbgvbhsrlpgdmjqwftvncz" => 23],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::rejection;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            rejection(part1("abcD")),
+            "line 1, column 4: expected a lowercase letter"
+        );
+        assert_eq!(
+            rejection(part1("abab")),
+            "no 4 letters in a row are all different"
+        );
+        assert_eq!(
+            rejection(part2("abcdefghijklm")),
+            "no 14 letters in a row are all different"
+        );
+    }
+}
@@ -1,10 +1,9 @@
-use std::fmt::Display;
-
 use bstr::{io::BufReadExt, ByteSlice};
 
 use crate::helper::parsing::IntoColumns;
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let nrows = input.byte_lines().count();
     let ncols = input.find_byte(b'\n').unwrap();
@@ -68,7 +67,7 @@ pub fn part1(input: &str) -> impl Display {
     visible
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let nrows = input.byte_lines().count();
     let ncols = input.find_byte(b'\n').unwrap();
@@ -120,3 +119,16 @@ pub fn part2(input: &str) -> impl Display {
 
     max
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390";
+
+examples! {
+    part1: [EXAMPLE => 21],
+    part2: [EXAMPLE => 8],
+}
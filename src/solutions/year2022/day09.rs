@@ -1,11 +1,9 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Display,
-};
+use std::collections::{HashMap, HashSet};
 
 use bstr::{io::BufReadExt, ByteSlice};
 
 use crate::helper::parsing::BytesAsNumber;
+use crate::solutions::Answer;
 
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 struct End {
@@ -81,7 +79,7 @@ impl End {
     }
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let mut head = End { x: 0, y: 0 };
     let mut tail = End { x: 0, y: 0 };
 
@@ -102,7 +100,7 @@ pub fn part1(input: &str) -> impl Display {
     visited.len()
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let mut head = End { x: 0, y: 0 };
     let mut tails: [End; 9] = Default::default();
 
@@ -126,3 +124,30 @@ pub fn part2(input: &str) -> impl Display {
 
     visited.len()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+#[cfg(test)]
+const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+examples! {
+    part1: [EXAMPLE => 13],
+    part2: [EXAMPLE => 1, LARGER_EXAMPLE => 36],
+}
@@ -1,69 +1,276 @@
-use std::{
-    fmt::Display,
-    io::{BufWriter, Stderr, Write},
-};
+use bstr::ByteSlice;
 
-use bstr::{io::BufReadExt, ByteSlice, B};
+use crate::helper::parsing::BytesAsNumber;
+use crate::solutions::{Answer, SolveError};
 
-use crate::helper::parsing::{BytesAsNumber, StripPrefixUnchecked};
-
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> Result<impl Into<Answer>, SolveError> {
+    let input = input.as_bytes();
     let mut cycle = 1;
     let mut reg = 1;
     let mut sum = 0;
 
-    input.as_bytes().lines().for_each(|line| {
+    for line in input.lines() {
         if (cycle - 20) % 40 == 0 {
             sum += cycle * reg;
         }
-        if let Some(x) = unsafe { line.strip_prefix_unchecked(b"addx") } {
+        if let Some(x) = addx(input, line)? {
             cycle += 1;
             if (cycle - 20) % 40 == 0 {
                 sum += cycle * reg;
             }
-            reg += x[1..].as_signed_num::<i32>();
+            reg += x;
         }
         cycle += 1;
-    });
-    sum
+    }
+    Ok(sum)
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> Result<impl Into<Answer>, SolveError> {
+    let input = input.as_bytes();
     let mut cycle: i32 = 0;
     let mut reg: i32 = 1;
     let mut crt = [32u8; 40 * 6 + 6];
 
     let mut idx = 0;
-    unsafe {
-        input.as_bytes().lines().for_each(|line| {
-            if reg.abs_diff(cycle) <= 1 {
-                *crt.get_unchecked_mut(idx) = b'#';
-            }
-            idx += 1;
-            if let Some(x) = line.strip_prefix(b"addx ") {
-                cycle += 1;
-                if cycle == 40 {
-                    cycle = 0;
-                    *crt.get_unchecked_mut(idx) = b'\n';
-                    idx += 1;
-                }
-                if reg.abs_diff(cycle) <= 1 {
-                    *crt.get_unchecked_mut(idx) = b'#';
-                }
-                idx += 1;
-                reg += x.as_signed_num::<i32>();
+    for line in input.lines() {
+        let mut draw = |idx: usize, pixel: u8| match crt.get_mut(idx) {
+            Some(slot) => {
+                *slot = pixel;
+                Ok(())
             }
+            None => Err(SolveError::at_slice(
+                input,
+                line,
+                "the program runs for more than 240 cycles",
+            )),
+        };
+
+        if reg.abs_diff(cycle) <= 1 {
+            draw(idx, b'#')?;
+        }
+        idx += 1;
+        if let Some(x) = addx(input, line)? {
             cycle += 1;
             if cycle == 40 {
                 cycle = 0;
-                *crt.get_unchecked_mut(idx) = b'\n';
+                draw(idx, b'\n')?;
                 idx += 1;
             }
-        });
-        let stdout = std::io::stdout().lock();
-        let mut writer = BufWriter::with_capacity(40 * 6 + 6, stdout);
-        writer.write_all(&crt);
+            if reg.abs_diff(cycle) <= 1 {
+                draw(idx, b'#')?;
+            }
+            idx += 1;
+            reg += x;
+        }
+        cycle += 1;
+        if cycle == 40 {
+            cycle = 0;
+            draw(idx, b'\n')?;
+            idx += 1;
+        }
     }
 
-    ""
+    Ok(Answer::Art(
+        String::from_utf8_lossy(&crt)
+            .trim_end_matches('\n')
+            .to_owned(),
+    ))
+}
+
+/// The value added by `line` if it is an `addx`, or `None` if it is a `noop`.
+fn addx(input: &[u8], line: &[u8]) -> Result<Option<i32>, SolveError> {
+    if line == b"noop" {
+        return Ok(None);
+    }
+    match line.strip_prefix(b"addx ") {
+        Some(x) if !x.is_empty() => Ok(Some(x.as_signed_num())),
+        _ => Err(SolveError::at_slice(
+            input,
+            line,
+            "expected `noop` or `addx <value>`",
+        )),
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+// The CRT draws dark pixels as spaces rather than the puzzle's dots
+#[cfg(test)]
+const EXAMPLE_SCREEN: &str = "\
+##  ##  ##  ##  ##  ##  ##  ##  ##  ##  
+###   ###   ###   ###   ###   ###   ### 
+####    ####    ####    ####    ####    
+#####     #####     #####     #####     
+######      ######      ######      ####
+#######       #######       #######     ";
+
+examples! {
+    part1: [EXAMPLE => 13140],
+    part2: [EXAMPLE => Answer::Art(EXAMPLE_SCREEN.to_owned())],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::rejection;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            rejection(part1("noop\naddx")),
+            "line 2, column 1: expected `noop` or `addx <value>`"
+        );
+        assert_eq!(
+            rejection(part2(&["noop"; 241].join("\n"))),
+            "line 241, column 1: the program runs for more than 240 cycles"
+        );
+        assert!(part2(&["noop"; 240].join("\n")).is_ok());
+    }
 }
@@ -1,11 +1,10 @@
-use std::fmt::Display;
-
 use bstr::ByteSlice;
 
 use crate::helper::parsing::BytesAsNumber;
+use crate::solutions::Answer;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
-enum Op {
+pub enum Op {
     Add(usize),
     Mul(usize),
     #[default]
@@ -13,7 +12,7 @@ enum Op {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
-struct Monkey {
+pub struct Monkey {
     pub items: Vec<usize>,
     pub op: Op,
     pub test: usize,
@@ -21,7 +20,7 @@ struct Monkey {
     pub f: usize,
 }
 
-fn parse(input: &[u8]) -> Vec<Monkey> {
+pub fn parse(input: &str) -> Vec<Monkey> {
     let mut monkeys = Vec::new();
     let mut monkey = Monkey::default();
     input
@@ -55,7 +54,7 @@ fn parse(input: &[u8]) -> Vec<Monkey> {
                 };
             }
             3 => {
-                monkey.test = line[20..].as_num();
+                monkey.test = line[21..].as_num();
             }
             4 => monkey.t = line[29..].as_num::<usize>(),
             5 => monkey.f = line[30..].as_num::<usize>(),
@@ -65,17 +64,9 @@ fn parse(input: &[u8]) -> Vec<Monkey> {
     monkeys
 }
 
-pub fn part1(input: &str) -> impl Display {
-    let mut monkeys = parse(input.as_bytes());
-    let mut inspects;
-
-    #[allow(clippy::correctness)]
-    /// SAFETY: This is safe as the Vec is filled right after set_len
-    unsafe {
-        inspects = Vec::with_capacity(monkeys.len());
-        inspects.set_len(monkeys.len());
-        inspects.fill(0);
-    }
+pub fn part1(monkeys: &[Monkey]) -> impl Into<Answer> {
+    let mut monkeys = monkeys.to_vec();
+    let mut inspects = vec![0; monkeys.len()];
 
     for _ in 0..20 {
         for i in 0..monkeys.len() {
@@ -106,17 +97,9 @@ pub fn part1(input: &str) -> impl Display {
     inspects[n - 1] * inspects[n - 2]
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let mut monkeys = parse(input.as_bytes());
-    let mut inspects;
-
-    #[allow(clippy::correctness)]
-    /// SAFETY: This is safe as the Vec is filled right after set_len
-    unsafe {
-        inspects = Vec::with_capacity(monkeys.len());
-        inspects.set_len(monkeys.len());
-        inspects.fill(0);
-    }
+pub fn part2(monkeys: &[Monkey]) -> impl Into<Answer> {
+    let mut monkeys = monkeys.to_vec();
+    let mut inspects = vec![0; monkeys.len()];
 
     let lcm: usize = monkeys.iter().map(|m| m.test).product();
 
@@ -154,3 +137,51 @@ pub fn part2(input: &str) -> impl Display {
     let n = inspects.len();
     inspects[n - 1] * inspects[n - 2]
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+examples! {
+    parse;
+    part1: [EXAMPLE => 10605],
+    part2: [EXAMPLE => 2713310158usize],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisors() {
+        // The divisor starts at column 21, after the space, which parsing used to include
+        let divisors = parse(EXAMPLE).iter().map(|m| m.test).collect::<Vec<_>>();
+        assert_eq!(divisors, [23, 19, 13, 17]);
+    }
+}
@@ -1,8 +1,10 @@
-use std::{collections::HashMap, fmt::Display};
+use std::collections::HashMap;
 
 use bstr::ByteSlice;
 use pathfinding::num_traits::SaturatingSub;
 
+use crate::solutions::Answer;
+
 const AROUND: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
 
 fn successors(matrix: &HashMap<(usize, usize), u8>, x: usize, y: usize) -> Vec<(usize, usize)> {
@@ -23,7 +25,7 @@ fn successors(matrix: &HashMap<(usize, usize), u8>, x: usize, y: usize) -> Vec<(
         .collect()
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let mut start: (usize, usize) = (0, 0);
     let mut end: (usize, usize) = (0, 0);
     let matrix = input
@@ -62,7 +64,7 @@ pub fn part1(input: &str) -> impl Display {
         - 1
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let mut end: (usize, usize) = (0, 0);
     let matrix = input
         .as_bytes()
@@ -101,3 +103,16 @@ pub fn part2(input: &str) -> impl Display {
         .min()
         .unwrap()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+examples! {
+    part1: [EXAMPLE => 31],
+    part2: [EXAMPLE => 29],
+}
@@ -1,7 +1,7 @@
-use std::fmt::Display;
-
 use bstr::ByteSlice;
 
+use crate::solutions::{Answer, Unimplemented};
+
 enum Value {
     Integer(usize),
     List(Vec<Value>),
@@ -32,7 +32,7 @@ impl From<&[u8]> for Value {
     }
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
 
     let mut sum = 0;
@@ -50,6 +50,6 @@ pub fn part1(input: &str) -> impl Display {
     sum
 }
 
-pub fn part2(input: &str) -> impl Display {
-    ""
+pub fn part2(input: &str) -> Unimplemented {
+    Unimplemented
 }
@@ -1,9 +1,5 @@
-use std::fmt::Display;
+use crate::solutions::Unimplemented;
 
-pub fn part1(input: &str) -> impl Display {
-    ""
-}
-
-pub fn part2(input: &str) -> impl Display {
-    ""
+pub fn part1(input: &str) -> Unimplemented {
+    Unimplemented
 }
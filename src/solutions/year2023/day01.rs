@@ -1,12 +1,13 @@
 use std::{
-    fmt::Display,
     mem::MaybeUninit,
     num::{NonZeroU8, NonZeroUsize},
 };
 
 use bstr::ByteSlice;
 
-pub fn part1(input: &str) -> impl Display {
+use crate::solutions::{Answer, SolveError};
+
+pub fn part1(input: &str) -> Result<impl Into<Answer>, SolveError> {
     let input = input.as_bytes();
     let mut lsum: u32 = 0;
     let mut rsum: u32 = 0;
@@ -15,7 +16,9 @@ pub fn part1(input: &str) -> impl Display {
     for line in input.lines() {
         num_lines += 1;
         let mut iter = line.iter().copied();
-        let first = unsafe { iter.find(|&b| b <= b'9').unwrap_unchecked() };
+        let first = iter
+            .find(|&b| b <= b'9')
+            .ok_or_else(|| SolveError::at_slice(input, line, "no digit on the line"))?;
         let last = iter.rfind(|&b| b <= b'9').unwrap_or(first);
         lsum += first as u32;
         rsum += last as u32;
@@ -23,10 +26,10 @@ pub fn part1(input: &str) -> impl Display {
 
     const COMPENSATION: u32 = 11 * b'0' as u32;
 
-    lsum * 10 + rsum - num_lines * COMPENSATION
+    Ok(lsum * 10 + rsum - num_lines * COMPENSATION)
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> Result<impl Into<Answer>, SolveError> {
     const OPTIONS: [&[u8]; 9] = [
         b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine",
     ];
@@ -36,24 +39,27 @@ pub fn part2(input: &str) -> impl Display {
     let mut rsum = 0;
     let mut lines = 0;
 
-    for mut line in input.lines() {
-        let mut first = b'0';
+    for whole in input.lines() {
+        let mut line = whole;
+        let mut first = None;
         while !line.is_empty() {
             if line[0] <= b'9' {
-                first = line[0];
+                first = Some(line[0]);
                 line = &line[1..];
                 break;
             }
 
             if line.len() >= 3 {
                 if let Some(val) = OPTIONS.iter().position(|&opt| line.starts_with(opt)) {
-                    first = val as u8 + b'1';
+                    first = Some(val as u8 + b'1');
                     break;
                 }
             }
 
             line = &line[1..];
         }
+        let first =
+            first.ok_or_else(|| SolveError::at_slice(input, whole, "no digit on the line"))?;
 
         let mut last = first;
         while !line.is_empty() {
@@ -80,5 +86,27 @@ pub fn part2(input: &str) -> impl Display {
 
     const COMPENSATION: u32 = 11 * b'0' as u32;
 
-    lsum * 10 + rsum - lines * COMPENSATION
+    Ok(lsum * 10 + rsum - lines * COMPENSATION)
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+#[cfg(test)]
+const SPELLED_EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+examples! {
+    part1: [EXAMPLE => 142],
+    part2: [EXAMPLE => 142, SPELLED_EXAMPLE => 281],
 }
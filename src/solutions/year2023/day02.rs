@@ -1,17 +1,17 @@
-use std::{fmt::Display, hint::unreachable_unchecked};
-
 use bstr::ByteSlice;
 
+use num_traits::PrimInt;
+
 use crate::helper::parsing::{BytesAsNumber, PartialConsume};
+use crate::solutions::{Answer, SolveError};
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> Result<impl Into<Answer>, SolveError> {
     const QUOTA: [u8; 3] = [12, 13, 14];
 
     let mut sum = 0;
     let input = input.as_bytes();
     'outer: for (game, line) in input.lines().enumerate() {
-        let line = &mut &line[6..];
-        line.skip_to_unit(b' ');
+        let line = &mut sets(input, line)?;
 
         while !line.is_empty() {
             let mut sets = line.skip_to_unit(b';');
@@ -23,23 +23,26 @@ pub fn part1(input: &str) -> impl Display {
                 sets.skip_to_unit(b' ');
 
                 let num = set.skip_to_unit(b' ');
-                let ty = match set.next().unwrap() {
-                    b'r' => {
+                let colour = set;
+                let ty = match set.next() {
+                    Some(b'r') => {
                         set.skip_n(3);
                         0
                     }
-                    b'g' => {
+                    Some(b'g') => {
                         set.skip_n(5);
                         1
                     }
-                    b'b' => {
+                    Some(b'b') => {
                         set.skip_n(4);
                         2
                     }
-                    _ => unsafe { unreachable_unchecked() },
+                    _ => return Err(unknown_colour(input, colour)),
                 };
 
-                sums[ty] += num.as_num::<u8>();
+                sums[ty] = sums[ty]
+                    .checked_add(count(input, num)?)
+                    .ok_or_else(|| SolveError::at_slice(input, num, "too many cubes in a set"))?;
 
                 if sums[ty] > QUOTA[ty] {
                     continue 'outer;
@@ -50,43 +53,106 @@ pub fn part1(input: &str) -> impl Display {
         sum += game + 1;
     }
 
-    sum
+    Ok(sum)
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> Result<impl Into<Answer>, SolveError> {
     let mut sum: u32 = 0;
     let input = input.as_bytes();
 
-    'outer: for mut line in input.lines() {
+    'outer: for game in input.lines() {
         let mut cut_off: &[u8] = &[];
 
-        let line = &mut &line[6..];
-        line.skip_to_unit(b' ');
+        let line = &mut sets(input, game)?;
 
         let mut mins: [u32; 3] = [0; 3];
         while !line.is_empty() {
             let num = line.skip_to_unit(b' ');
 
-            let ty = match line.next().unwrap() {
-                b'r' => {
+            let colour = *line;
+            let ty = match line.next() {
+                Some(b'r') => {
                     line.skip_n(2);
                     0
                 }
-                b'g' => {
+                Some(b'g') => {
                     line.skip_n(4);
                     1
                 }
-                b'b' => {
+                Some(b'b') => {
                     line.skip_n(3);
                     2
                 }
-                _ => unsafe { unreachable_unchecked() },
+                _ => return Err(unknown_colour(input, colour)),
             };
 
-            mins[ty] = mins[ty].max(num.as_num());
+            mins[ty] = mins[ty].max(count(input, num)?);
         }
-        sum += mins[0] * mins[1] * mins[2];
+        sum = mins[0]
+            .checked_mul(mins[1])
+            .and_then(|power| power.checked_mul(mins[2]))
+            .and_then(|power| sum.checked_add(power))
+            .ok_or_else(|| SolveError::at_slice(input, game, "the sum of powers is too large"))?;
     }
 
-    sum
+    Ok(sum)
+}
+
+/// What follows `Game <id>: ` on `line`.
+fn sets<'a>(input: &[u8], line: &'a [u8]) -> Result<&'a [u8], SolveError> {
+    line.strip_prefix(b"Game ")
+        .and_then(|rest| Some(&rest[rest.find(b": ")? + 2..]))
+        .ok_or_else(|| SolveError::at_slice(input, line, "expected `Game <id>: `"))
+}
+
+fn count<T: PrimInt + 'static>(input: &[u8], num: &[u8]) -> Result<T, SolveError> {
+    num.try_as_num()
+        .ok_or_else(|| SolveError::at_slice(input, num, "expected a number of cubes"))
+}
+
+fn unknown_colour(input: &[u8], colour: &[u8]) -> SolveError {
+    SolveError::at_slice(input, colour, "expected red, green or blue")
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+examples! {
+    part1: [EXAMPLE => 8],
+    part2: [EXAMPLE => 2286],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::rejection;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            rejection(part1("Game 1: 1 red\nGame")),
+            "line 2, column 1: expected `Game <id>: `"
+        );
+        assert_eq!(
+            rejection(part2("Game 1: 1 red, two blue")),
+            "line 1, column 16: expected a number of cubes"
+        );
+        assert_eq!(
+            rejection(part1("Game 1: 300 blue")),
+            "line 1, column 9: expected a number of cubes"
+        );
+        assert_eq!(
+            rejection(part1("Game 1: 10 red, 250 red")),
+            "line 1, column 17: too many cubes in a set"
+        );
+        assert_eq!(
+            rejection(part2("Game 1: 70000 red, 70000 green, 70000 blue")),
+            "line 1, column 1: the sum of powers is too large"
+        );
+    }
 }
@@ -1,8 +1,9 @@
-use std::{collections::VecDeque, fmt::Display};
+use std::collections::VecDeque;
 
 use bstr::ByteSlice;
 
 use crate::helper::{matrix::Matrix, parsing::BytesAsNumber};
+use crate::solutions::Answer;
 
 fn to_board(input: &[u8]) -> Matrix<u8> {
     let mut out = Matrix::new();
@@ -29,7 +30,7 @@ fn sym(b: u8) -> bool {
     b != b'.' && !b.is_ascii_digit()
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let board = to_board(input);
 
@@ -65,7 +66,7 @@ pub fn part1(input: &str) -> impl Display {
     sum
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let board = to_board(input);
 
@@ -122,3 +123,21 @@ pub fn part2(input: &str) -> impl Display {
 
     sum
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+examples! {
+    part1: [EXAMPLE => 4361],
+    part2: [EXAMPLE => 467835],
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Display, ops::BitOr};
+use std::ops::BitOr;
 
 use bstr::ByteSlice;
 
@@ -6,8 +6,9 @@ use crate::helper::{
     parsing::{BytesAsNumber, PartialConsume},
     util::{hash_4_separated_ascii_digit_pairs, hash_ascii_digit_pair},
 };
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let mut input = input.as_bytes();
     let mut sum: u32 = 0;
 
@@ -55,7 +56,7 @@ pub fn part1(input: &str) -> impl Display {
     sum
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let mut input = input.as_bytes();
     let mut card_info: Vec<u128> = Vec::new();
     let mut cards: [u32; 256] = [0; 256];
@@ -132,3 +133,17 @@ pub fn part2(input: &str) -> impl Display {
 
     cards.iter().sum::<u32>()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+examples! {
+    part1: [EXAMPLE => 13],
+    part2: [EXAMPLE => 30],
+}
@@ -1,10 +1,11 @@
 use bstr::ByteSlice;
 use rayon::iter::{IntoParallelIterator, ParallelDrainRange, ParallelIterator};
-use std::{fmt::Display, ops::Range};
+use std::ops::Range;
 
 use crate::helper::parsing::{BytesAsNumber, PartialConsume};
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let mut input = input.as_bytes();
 
     let mut line_iter = input.lines();
@@ -55,7 +56,7 @@ pub fn part1(input: &str) -> impl Display {
 }
 
 // This is an abomination
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let mut input = input.as_bytes();
 
     let mut line_iter = input.lines();
@@ -119,3 +120,99 @@ pub fn part2(input: &str) -> impl Display {
             .unwrap()
     })
 }
+
+/// Maps whole ranges of seeds through each section at once, splitting them where they only
+/// partly overlap a mapping, instead of mapping every seed.
+pub fn part2_ranges(input: &str) -> impl Into<Answer> {
+    let mut sections = input.split("\n\n");
+    let seeds = sections.next().unwrap()[7..]
+        .split_ascii_whitespace()
+        .map(|n| n.parse::<u64>().unwrap())
+        .collect::<Vec<_>>();
+    let mut ranges = seeds
+        .chunks_exact(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect::<Vec<Range<u64>>>();
+
+    for section in sections {
+        let maps = section
+            .lines()
+            .skip(1)
+            .map(|line| {
+                let mut nums = line.split(' ').map(|n| n.parse::<u64>().unwrap());
+                let (dst, src, len) = (
+                    nums.next().unwrap(),
+                    nums.next().unwrap(),
+                    nums.next().unwrap(),
+                );
+                (src..src + len, dst)
+            })
+            .collect::<Vec<_>>();
+
+        let mut mapped = Vec::new();
+        while let Some(range) = ranges.pop() {
+            let overlap = maps
+                .iter()
+                .find(|(src, _)| src.start < range.end && range.start < src.end);
+            let Some((src, dst)) = overlap else {
+                mapped.push(range);
+                continue;
+            };
+
+            let start = range.start.max(src.start);
+            let end = range.end.min(src.end);
+            mapped.push(start - src.start + dst..end - src.start + dst);
+            if range.start < start {
+                ranges.push(range.start..start);
+            }
+            if end < range.end {
+                ranges.push(end..range.end);
+            }
+        }
+        ranges = mapped;
+    }
+
+    ranges.iter().map(|range| range.start).min().unwrap()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+examples! {
+    part1: [EXAMPLE => 35],
+    part2: [EXAMPLE => 46],
+    part2_ranges: [EXAMPLE => 46],
+}
@@ -1,39 +1,81 @@
-use std::fmt::Display;
-
 use bstr::ByteSlice;
 
 use crate::helper::{
     parsing::BytesAsNumber,
     util::{binary_search_by, partition_point_high},
 };
+use num_traits::PrimInt;
+
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut lines = input.lines();
 
-    let times = lines.next().unwrap()[12..].as_nums::<u32>();
-    let distances = lines.next().unwrap()[12..].as_nums::<u32>();
+    let times = numbers(lines.next().unwrap()).as_nums::<u32>();
+    let distances = numbers(lines.next().unwrap()).as_nums::<u32>();
 
     let mut prod = 1;
     for (t, d) in times.zip(distances) {
-        let high = partition_point_high(d / t..d.div_ceil(2), |i| (i + d / i) < t);
-        let low = partition_point_high(d / t..high, |i| (i + d / i) >= t);
-
-        prod *= high - low;
+        prod *= ways_to_win(t, d);
     }
 
     prod
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut lines = input.lines();
 
-    let t: u64 = lines.next().unwrap()[12..].as_num_checked();
-    let d: u64 = lines.next().unwrap()[12..].as_num_checked();
+    let t: u64 = numbers(lines.next().unwrap()).as_num_checked();
+    let d: u64 = numbers(lines.next().unwrap()).as_num_checked();
+
+    ways_to_win(t, d)
+}
+
+/// What follows the label of a line such as `Time:      7  15   30`.
+fn numbers(line: &[u8]) -> &[u8] {
+    &line[line.find_byte(b':').map_or(0, |idx| idx + 1)..]
+}
+
+/// The number of times to hold the button for that beat distance `d` in a race of time `t`.
+fn ways_to_win<T: PrimInt>(t: T, d: T) -> T {
+    let two = T::one() + T::one();
+    if t < two {
+        return T::zero();
+    }
 
-    let high = partition_point_high(d / t..d.div_ceil(2), |i| (i + d / i) < t);
-    let low = partition_point_high(d / t..high, |i| (i + d / i) >= t);
+    // Holding for `i` wins when `i * (t - i) > d`, which is `i + d / i < t` in integers. The
+    // winning times are centred on `t / 2`, so searching from there finds the edges whatever the
+    // input, and none are at or below `d / t`
+    let high = partition_point_high(t / two..t, |i| (i + d / i) < t);
+    let low = (d / t).max(T::one()).min(high);
+    let low = partition_point_high(low..high, |i| (i + d / i) >= t);
 
     high - low
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+examples! {
+    part1: [EXAMPLE => 288],
+    part2: [EXAMPLE => 71503],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_race() {
+        for t in 0..40u32 {
+            for d in 0..t * t {
+                let expected = (0..=t).filter(|i| i * (t - i) > d).count() as u32;
+                assert_eq!(ways_to_win(t, d), expected, "t = {t}, d = {d}");
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use std::{cmp::Ordering, fmt::Display};
+use std::cmp::Ordering;
 
 use bstr::ByteSlice;
 
 use crate::helper::parsing::{BytesAsNumber, PartialConsume};
+use crate::solutions::Answer;
 
 const P1_VALUES: [u8; 91] = {
     let mut values = [0; 91];
@@ -165,7 +166,11 @@ fn p2_typer(valued: [u8; 5]) -> Type {
     Type::from_cards_and_kinds(cards, kinds)
 }
 
-fn solve(input: &str, card_values: [u8; 91], card_typer: impl Fn([u8; 5]) -> Type) -> impl Display {
+fn solve(
+    input: &str,
+    card_values: [u8; 91],
+    card_typer: impl Fn([u8; 5]) -> Type,
+) -> impl Into<Answer> {
     let input = input.as_bytes();
 
     let mut hands = Vec::new();
@@ -188,10 +193,23 @@ fn solve(input: &str, card_values: [u8; 91], card_typer: impl Fn([u8; 5]) -> Typ
     ans
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     solve(input, P1_VALUES, p1_typer)
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     solve(input, P2_VALUES, p2_typer)
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+examples! {
+    part1: [EXAMPLE => 6440],
+    part2: [EXAMPLE => 5905],
+}
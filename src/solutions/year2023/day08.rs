@@ -1,22 +1,30 @@
-use std::{collections::HashMap, fmt::Display, time::Instant};
+use std::{collections::HashMap, time::Instant};
 
 use bstr::ByteSlice;
 use rustc_hash::FxHashMap;
 
 use crate::helper::{parsing::PartialConsume, util::IntegerIteratorExt};
+use crate::solutions::Answer;
 
 type FakeHashMap<T> = [[[T; 26]; 26]; 26];
 
-pub fn part1(input: &str) -> impl Display {
+pub struct Network {
+    instructions: Vec<u8>,
+    map: FakeHashMap<([u8; 3], [u8; 3])>,
+    starts: Vec<[u8; 3]>,
+}
+
+pub fn parse(input: &str) -> Network {
     let input = input.as_bytes();
 
     let mut lines = input.lines();
 
-    let instructions = lines.next().unwrap();
+    let instructions = lines.next().unwrap().to_vec();
 
     lines.next();
 
     let mut map: FakeHashMap<([u8; 3], [u8; 3])> = Default::default();
+    let mut starts: Vec<[u8; 3]> = Vec::new();
     for mut line in lines {
         let start = line.skip_to_unit(b' ');
 
@@ -25,10 +33,26 @@ pub fn part1(input: &str) -> impl Display {
         line = &line[1..];
         let right = line.skip_to_unit(b')');
 
+        if start[2] == b'A' {
+            starts.push(start.try_into().unwrap());
+        }
+
         map[(start[0] - b'A') as usize][(start[1] - b'A') as usize][(start[2] - b'A') as usize] =
             (left.try_into().unwrap(), right.try_into().unwrap());
     }
 
+    Network {
+        instructions,
+        map,
+        starts,
+    }
+}
+
+pub fn part1(network: &Network) -> impl Into<Answer> {
+    let Network {
+        instructions, map, ..
+    } = network;
+
     let mut curr = *b"AAA";
 
     let mut steps = 0;
@@ -47,32 +71,11 @@ pub fn part1(input: &str) -> impl Display {
     steps
 }
 
-pub fn part2(input: &str) -> impl Display {
-    let input = input.as_bytes();
-
-    let mut lines = input.lines();
-
-    let instructions = lines.next().unwrap();
-
-    lines.next();
-
-    let mut map: FakeHashMap<([u8; 3], [u8; 3])> = Default::default();
-    let mut starts: Vec<[u8; 3]> = Vec::new();
-    for mut line in lines {
-        let start = line.skip_to_unit(b' ');
-
-        line.skip_to_unit(b'(');
-        let left = line.skip_to_unit(b',');
-        line = &line[1..];
-        let right = line.skip_to_unit(b')');
-
-        if start[2] == b'A' {
-            starts.push(start.try_into().unwrap());
-        }
-
-        map[(start[0] - b'A') as usize][(start[1] - b'A') as usize][(start[2] - b'A') as usize] =
-            (left.try_into().unwrap(), right.try_into().unwrap());
-    }
+pub fn part2(network: &Network) -> impl Into<Answer> {
+    let Network {
+        instructions, map, ..
+    } = network;
+    let mut starts = network.starts.clone();
 
     let mut steps = 0;
     let mut reached: Vec<u64> = vec![0; starts.len()];
@@ -109,3 +112,29 @@ pub fn part2(input: &str) -> impl Display {
 
     cycles.into_iter().lcm().unwrap()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+#[cfg(test)]
+const REPEATING_EXAMPLE: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+// Part 2 has no example, as its node names contain digits while nodes are indexed by letter.
+examples! {
+    parse;
+    part1: [EXAMPLE => 2, REPEATING_EXAMPLE => 6],
+}
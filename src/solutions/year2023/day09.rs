@@ -1,17 +1,17 @@
-use std::fmt::Display;
-
 use bstr::ByteSlice;
 
 use crate::helper::{parsing::BytesAsNumber, util::CollectToArray};
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut sum = 0;
 
-    let mut seq = [0; 21];
+    let mut buf = [0; 21];
 
     for mut line in input.lines() {
-        line.as_signed_nums().collect_into_array(&mut seq);
+        let len = line.as_signed_nums().collect_into_array(&mut buf);
+        let seq = &mut buf[..len];
 
         let mut layers = 0;
 
@@ -31,15 +31,16 @@ pub fn part1(input: &str) -> impl Display {
     sum
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut sum = 0;
 
-    let mut seq = [0; 21];
+    let mut buf = [0; 21];
     let mut lmosts = [0; 21];
 
     for mut line in input.lines() {
-        line.as_signed_nums().collect_into_array(&mut seq);
+        let len = line.as_signed_nums().collect_into_array(&mut buf);
+        let seq = &mut buf[..len];
 
         let mut layers = 0;
 
@@ -69,3 +70,14 @@ pub fn part2(input: &str) -> impl Display {
 
     sum
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+examples! {
+    part1: [EXAMPLE => 114],
+    part2: [EXAMPLE => 2],
+}
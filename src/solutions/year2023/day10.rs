@@ -1,7 +1,7 @@
 use rustc_hash::FxHashSet;
-use std::fmt::Display;
 
 use crate::helper::matrix::Matrix;
+use crate::solutions::Answer;
 use bstr::ByteSlice;
 use pathfinding::prelude::*;
 
@@ -95,7 +95,7 @@ fn reachable(mut from: ((usize, usize), u8), mut to: ((usize, usize), u8)) -> bo
     fd.contains(&d) && td.contains(&d)
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut board = to_board(input);
 
@@ -135,7 +135,7 @@ pub fn part1(input: &str) -> impl Display {
     len / 2
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut board = to_board(input);
 
@@ -320,3 +320,41 @@ pub fn part2(input: &str) -> impl Display {
 
     tiles.len()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+#[cfg(test)]
+const ENCLOSED_EXAMPLE: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+#[cfg(test)]
+const LARGER_ENCLOSED_EXAMPLE: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+examples! {
+    part1: [EXAMPLE => 8],
+    part2: [ENCLOSED_EXAMPLE => 4, LARGER_ENCLOSED_EXAMPLE => 8],
+}
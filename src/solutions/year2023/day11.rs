@@ -1,13 +1,12 @@
-use std::fmt::Display;
-
 use bitvec::{bitvec, order::LocalBits, vec::BitVec};
 use bstr::ByteSlice;
 use rustc_hash::FxHashSet;
 use set_builder::set;
 
 use crate::helper::matrix::Matrix;
+use crate::solutions::Answer;
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut mat = Matrix::from_iter(input.lines());
 
@@ -54,7 +53,7 @@ pub fn part1(input: &str) -> impl Display {
     sum
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
     let mut mat = Matrix::from_iter(input.lines());
 
@@ -100,3 +99,21 @@ pub fn part2(input: &str) -> impl Display {
 
     sum
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+examples! {
+    part1: [EXAMPLE => 374],
+    part2: [EXAMPLE => 82000210],
+}
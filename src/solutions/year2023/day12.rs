@@ -1,4 +1,4 @@
-use std::{fmt::Display, hash::Hasher, rc::Rc};
+use std::{hash::Hasher, rc::Rc};
 
 use bitvec::{bitarr, bitvec, order::LocalBits, view::BitView};
 use bstr::ByteSlice;
@@ -9,6 +9,7 @@ use crate::helper::{
     parsing::{BytesAsNumber, PartialConsume},
     util::{CollectToArray, Timer},
 };
+use crate::solutions::Answer;
 
 type Cache = FxHashMap<u64, u64>;
 
@@ -67,7 +68,7 @@ fn solve(mut springs: &[u8], lengths: &[u8], cache: &mut Cache) -> u64 {
     ans
 }
 
-pub fn part1(input: &str) -> impl Display {
+pub fn part1(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
 
     let mut sum = 0;
@@ -124,7 +125,7 @@ pub fn part1(input: &str) -> impl Display {
     sum
 }
 
-pub fn part2(input: &str) -> impl Display {
+pub fn part2(input: &str) -> impl Into<Answer> {
     let input = input.as_bytes();
 
     let mut sum: u128 = 0;
@@ -146,10 +147,22 @@ pub fn part2(input: &str) -> impl Display {
 
         sum += solve(springs.as_slice(), &lengths, &mut cache) as u128;
 
-        unsafe {
-            springs.set_len(0);
-        }
+        springs.clear();
     }
 
     sum
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+examples! {
+    part1: [EXAMPLE => 21],
+    part2: [EXAMPLE => 525152],
+}
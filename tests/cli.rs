@@ -0,0 +1,493 @@
+// These run the CLI as a child process, which Miri cannot do
+#![cfg(not(miri))]
+
+mod mock;
+#[path = "../src/test_support.rs"]
+mod test_support;
+
+use std::{
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+use mock::MockServer;
+use test_support::TempDir;
+
+/// The example from 2023 day 1, whose part 1 answer is 142.
+const INPUT: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+/// A scratch directory to run the CLI in, so that inputs and logs do not leak between tests.
+struct Workspace {
+    dir: TempDir,
+}
+
+impl Workspace {
+    fn new(name: &str) -> Self {
+        Self {
+            dir: TempDir::new(&format!("cli-{name}")),
+        }
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.dir.join(path)
+    }
+
+    fn read(&self, path: &str) -> String {
+        std::fs::read_to_string(self.path(path)).unwrap()
+    }
+
+    fn write(&self, path: &str, contents: &str) {
+        let path = self.path(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    /// Runs the CLI against `server`, with the session token it accepts.
+    fn run(&self, server: &MockServer, args: &[&str]) -> Output {
+        self.command(server)
+            .args(["--session", MockServer::SESSION])
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    /// Runs the CLI against `server` without any session token.
+    fn run_offline(&self, server: &MockServer, args: &[&str]) -> Output {
+        self.command(server).args(args).output().unwrap()
+    }
+
+    fn command(&self, server: &MockServer) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_aoc-rs"));
+        command
+            .current_dir(self.dir.path())
+            .env("AOC_BASE_URL", server.url())
+            .env("XDG_CONFIG_HOME", self.path("config"))
+            .env_remove("AOC_SESSION");
+        command
+    }
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn fetch() {
+    let workspace = Workspace::new("fetch");
+    let server = MockServer::start();
+    server.input(2023, 1, INPUT);
+
+    let output = workspace.run(&server, &["fetch", "2023", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(workspace.read("inputs/2023/1.txt"), INPUT);
+
+    // Cached inputs are not downloaded again, even without a session
+    let output = workspace.run_offline(&server, &["fetch", "2023", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("already in"));
+    assert_eq!(server.requests(), ["GET /2023/day/1/input"]);
+
+    // Days that are not unlocked yet fail with the server's explanation
+    let output = workspace.run(&server, &["fetch", "2023", "1..=2"]);
+    assert!(!output.status.success());
+    let stderr = stderr(&output);
+    assert!(stderr.contains("2023 day 2:"), "{stderr}");
+    assert!(
+        stderr.contains("404: Please don't repeatedly request this endpoint"),
+        "{stderr}"
+    );
+    assert!(!workspace.path("inputs/2023/2.txt").exists());
+}
+
+#[test]
+fn fetch_replaces_invalid_inputs() {
+    let workspace = Workspace::new("invalid");
+    let server = MockServer::start();
+    server.input(2023, 1, INPUT);
+    workspace.write("inputs/2023/1.txt", "<!DOCTYPE html>\n");
+
+    let output = workspace.run(&server, &["fetch", "2023", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("is an HTML page"));
+    assert_eq!(workspace.read("inputs/2023/1.txt"), INPUT);
+}
+
+#[test]
+fn rejected_session() {
+    let workspace = Workspace::new("session");
+    let server = MockServer::start();
+    server.input(2023, 1, INPUT);
+
+    let output = workspace.run_offline(
+        &server,
+        &["--session", "fedcba9876543210", "fetch", "2023", "1"],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("400: Puzzle inputs differ by user"));
+    assert!(!workspace.path("inputs/2023/1.txt").exists());
+}
+
+#[test]
+fn run_offline() {
+    let workspace = Workspace::new("offline");
+    let server = MockServer::start();
+    workspace.write("inputs/2023/1.txt", INPUT);
+
+    let output = workspace.run_offline(&server, &["run", "2023", "1", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Answer: 142"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn submit() {
+    let workspace = Workspace::new("submit");
+    let server = MockServer::start();
+    server.input(2023, 1, INPUT).answer(2023, 1, 1, "142");
+
+    let output = workspace.run(&server, &["submit", "2023", "1", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Answer accepted"));
+    assert!(workspace
+        .read("answers/2023.toml")
+        .contains("part1 = \"142\""));
+    assert!(workspace
+        .read("submissions/2023.toml")
+        .contains("verdict = \"correct\""));
+
+    // Accepted answers are not submitted again
+    let output = workspace.run(&server, &["submit", "2023", "1", "1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("`142` was already accepted"));
+    assert_eq!(
+        server.requests(),
+        ["GET /2023/day/1/input", "POST /2023/day/1/answer"]
+    );
+}
+
+#[test]
+fn submit_wrong_answer() {
+    let workspace = Workspace::new("wrong");
+    let server = MockServer::start();
+    server.input(2023, 1, INPUT).answer(2023, 1, 1, "100");
+
+    let output = workspace.run(&server, &["submit", "2023", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("(too high), wait 1m before trying again"));
+    let log = workspace.read("submissions/2023.toml");
+    assert!(log.contains("verdict = \"too_high\""), "{log}");
+    assert!(log.contains("wait = 60"), "{log}");
+    assert!(!workspace.path("answers/2023.toml").exists());
+
+    // The same answer is refused locally instead of being submitted again
+    let output = workspace.run(&server, &["submit", "2023", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("`142` was already submitted and was too high"));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn submit_rate_limited() {
+    let workspace = Workspace::new("rate-limited");
+    let server = MockServer::start();
+    server
+        .input(2023, 1, INPUT)
+        .answer(2023, 1, 1, "142")
+        .rate_limit("1m 5s");
+
+    let output = workspace.run(&server, &["submit", "2023", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("rate limited, wait 1m 5s"));
+    let log = workspace.read("submissions/2023.toml");
+    assert!(log.contains("verdict = \"rate_limited\""), "{log}");
+    assert!(log.contains("wait = 65"), "{log}");
+
+    // The cooldown is respected without asking the server, for every part as it applies to the
+    // whole account
+    for part in ["1", "2"] {
+        let output = workspace.run(&server, &["submit", "2023", "1", part]);
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains("not submitting, wait 1m"));
+    }
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn submit_cooldown_across_years() {
+    let workspace = Workspace::new("cooldown-across-years");
+    let server = MockServer::start();
+    workspace.write("inputs/2023/1.txt", INPUT);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    workspace.write(
+        "submissions/2022.toml",
+        &format!(
+            "[[day05.part1]]\nanswer = \"CMZ\"\nverdict = \"wrong\"\n\
+             submitted_at = {now}\nwait = 60\n"
+        ),
+    );
+
+    // The server rate-limits the account, so a wrong answer in 2022 holds back 2023 too
+    let output = workspace.run(&server, &["submit", "2023", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("not submitting, wait"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn profiles() {
+    let workspace = Workspace::new("profiles");
+    let server = MockServer::start();
+    server.input(2023, 1, INPUT);
+    workspace.write("profiles.toml", "[alice]\n\n[bob]\ninputs = \"bob\"\n");
+
+    let output = workspace
+        .command(&server)
+        .env("AOC_SESSION_ALICE", MockServer::SESSION)
+        .args(["--profile", "alice", "fetch", "2023", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(workspace.read("profiles/alice/inputs/2023/1.txt"), INPUT);
+    assert!(!workspace.path("inputs").exists());
+
+    // Bob's session is not configured, so his input has to be there already
+    let output = workspace.run_offline(&server, &["--profile", "bob", "run", "2023", "1", "1"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("`AOC_SESSION_BOB`"));
+
+    workspace.write("bob/2023/1.txt", "1abc2\n");
+    workspace.write(
+        "profiles/alice/answers/2023.toml",
+        "[day01]\npart1 = \"142\"\n",
+    );
+    workspace.write(
+        "profiles/bob/answers/2023.toml",
+        "[day01]\npart1 = \"13\"\n",
+    );
+    let output = workspace.run_offline(&server, &["check", "2023", "1", "1", "--all-profiles"]);
+    assert!(!output.status.success());
+    let report = stderr(&output);
+    assert!(report.contains("Profile `alice`"), "{report}");
+    assert!(report.contains("Profile `bob`"), "{report}");
+    assert!(report.contains("1 of 2 profiles failed: bob"), "{report}");
+
+    let output = workspace.run_offline(&server, &["--profile", "carol", "run", "2023"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("no profile `carol`, expected one of alice, bob"));
+}
+
+#[test]
+fn input_override() {
+    let workspace = Workspace::new("override");
+    let server = MockServer::start();
+    workspace.write("example.txt", "1abc2\n");
+
+    let output = workspace.run_offline(
+        &server,
+        &["run", "2023", "1", "1", "--input", "example.txt"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Answer: 12"));
+
+    let mut child = workspace
+        .command(&server)
+        .args(["run", "2023", "1", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(INPUT.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(stderr(&output).contains("142"), "{}", stderr(&output));
+
+    // Neither the cache nor the server are touched
+    assert!(!workspace.path("inputs").exists());
+    assert!(server.requests().is_empty());
+
+    let output =
+        workspace.run_offline(&server, &["run", "2023", "1..=2", "--input", "example.txt"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("single day"));
+}
+
+#[test]
+fn json_records() {
+    let workspace = Workspace::new("json");
+    let server = MockServer::start();
+    workspace.write("inputs/2023/1.txt", INPUT);
+    workspace.write("answers/2023.toml", "[day01]\npart1 = \"142\"\n");
+
+    let output = workspace.run_offline(&server, &["check", "2023", "1", "1", "--format", "json"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let records = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(records.len(), 1, "{stdout}");
+    let record: serde_json::Value = serde_json::from_str(records[0]).unwrap();
+    assert_eq!(record["year"], 2023);
+    assert_eq!(record["day"], 1);
+    assert_eq!(record["part"], 1);
+    assert_eq!(record["answer"], "142");
+    assert_eq!(record["status"], "correct");
+    assert!(record["duration_ns"].is_u64());
+
+    let output = workspace.run_offline(
+        &server,
+        &[
+            "bench", "2023", "1", "1", "-n", "5", "--warmup", "0s", "--label", "json", "--format",
+            "json",
+        ],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    let record: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(record["answer"], "142");
+    assert_eq!(record["stats"]["runs"], 5);
+    assert_eq!(record["duration_ns"], record["stats"]["mean_ns"]);
+}
+
+#[test]
+fn variants() {
+    let workspace = Workspace::new("variants");
+    let server = MockServer::start();
+    let almanac = "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15\n";
+    workspace.write("inputs/2023/5.txt", almanac);
+    workspace.write("answers/2023.toml", "[day05]\npart2 = \"57\"\n");
+
+    let output = workspace.run_offline(&server, &["run", "2023", "5", "2", "--variant", "ranges"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Answer: 57"));
+
+    let output = workspace.run_offline(&server, &["run", "2023", "5", "2", "--variant", "simd"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("no variant `simd`, expected one of default, ranges"));
+
+    // Parts without the variant are skipped, rather than reported as not implemented
+    let output = workspace.run_offline(&server, &["run", "2023", "5", "--variant", "ranges"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let report = stderr(&output);
+    assert!(report.contains("2 (ranges)  57"), "{report}");
+    assert!(!report.contains("  1  "), "{report}");
+
+    let output = workspace.run_offline(&server, &["check", "2023", "5", "2"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("2 correct, 0 incorrect"));
+}
+
+#[test]
+fn isolation() {
+    let workspace = Workspace::new("isolation");
+    let server = MockServer::start();
+    workspace.write("empty.txt", "\n");
+    workspace.write("seeds.txt", "seeds: 0 20000000\n");
+
+    // Both parts panic on the missing seeds, and the second still runs after the first
+    let output = workspace.run_offline(&server, &["run", "2023", "5", "--input", "empty.txt"]);
+    assert!(!output.status.success());
+    let report = stderr(&output);
+    assert!(report.contains("2 parts failed:"), "{report}");
+    assert!(
+        report.contains("2023 day 5 part 2: panicked at")
+            && report.contains("called `Option::unwrap()` on a `None` value"),
+        "{report}"
+    );
+
+    // `AAA` only leads back to itself, so the walk to `ZZZ` never ends however fast the machine is
+    workspace.write("cycle.txt", "L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n");
+    let output = workspace.run_offline(
+        &server,
+        &[
+            "run",
+            "2023",
+            "8",
+            "1",
+            "--input",
+            "cycle.txt",
+            "--timeout",
+            "50ms",
+        ],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("2023 day 8 part 1 did not finish within 50.00ms"));
+
+    let output = workspace.run_offline(
+        &server,
+        &[
+            "run",
+            "2023",
+            "5",
+            "--input",
+            "seeds.txt",
+            "--memory-limit",
+            "16",
+        ],
+    );
+    assert!(!output.status.success());
+    let report = stderr(&output);
+    assert!(report.contains("   1  0 "), "{report}");
+    assert!(
+        report.contains("2023 day 5 part 2: tried to allocate more than 16 MiB"),
+        "{report}"
+    );
+}
+
+#[test]
+fn malformed_input() {
+    let workspace = Workspace::new("malformed");
+    let server = MockServer::start();
+    workspace.write("games.txt", "Game 1: 3 blue, 4 red\nGame 2: 1 purple\n");
+
+    let output = workspace.run_offline(&server, &["run", "2023", "2", "1", "--input", "games.txt"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output).trim(),
+        "error: 2023 day 2 part 1 rejected the input: line 2, column 11: expected red, green or blue"
+    );
+
+    let output = workspace.run_offline(&server, &["run", "2023", "2", "--input", "games.txt"]);
+    assert!(!output.status.success());
+    let report = stderr(&output);
+    assert!(report.contains("rejected input"), "{report}");
+    assert!(
+        report.contains(
+            "2023 day 2 part 2: rejected the input: line 2, column 11: expected red, green or blue"
+        ),
+        "{report}"
+    );
+
+    let output = workspace.run_offline(
+        &server,
+        &["bench", "2023", "2", "2", "--input", "games.txt"],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("2023 day 2 part 2 rejected the input: line 2, column 11"));
+}
+
+#[test]
+fn missing_solutions() {
+    let workspace = Workspace::new("missing");
+    let server = MockServer::start();
+
+    let output = workspace.run_offline(&server, &["run", "2023", "25", "2"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        "error: 2023 day 25 has no part 2, as it only has one part\n"
+    );
+
+    // Day 25 is listed with its single part, rather than a second one that is not implemented
+    let output = workspace.run_offline(&server, &["list", "2023"]);
+    assert!(stderr(&output).contains("2023   25  no      -\n"));
+    assert!(server.requests().is_empty());
+}
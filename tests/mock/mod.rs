@@ -0,0 +1,206 @@
+//! A stand-in for the Advent of Code website, serving fixture inputs and judging submissions with
+//! the same messages as the real one, so that the CLI can be tested without network access.
+
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+};
+
+pub struct MockServer {
+    url: String,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    inputs: HashMap<(i32, i32), String>,
+    answers: HashMap<(i32, i32, i32), String>,
+    rate_limit: Option<String>,
+    requests: Vec<String>,
+}
+
+impl MockServer {
+    /// The session token that the server accepts.
+    pub const SESSION: &'static str = "0123456789abcdef";
+
+    /// Starts serving on a free port, until the test process exits.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let shared = Arc::clone(&state);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &shared);
+            }
+        });
+
+        Self { url, state }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn input(&self, year: i32, day: i32, input: &str) -> &Self {
+        let mut state = self.state.lock().unwrap();
+        state.inputs.insert((year, day), input.to_owned());
+        self
+    }
+
+    /// Sets the answer that submissions are judged against. Parts without one are treated as
+    /// already completed.
+    pub fn answer(&self, year: i32, day: i32, part: i32, answer: &str) -> &Self {
+        let mut state = self.state.lock().unwrap();
+        state.answers.insert((year, day, part), answer.to_owned());
+        self
+    }
+
+    /// Rejects the next submission as too recent, with `wait` such as `1m 5s` left.
+    pub fn rate_limit(&self, wait: &str) -> &Self {
+        self.state.lock().unwrap().rate_limit = Some(wait.to_owned());
+        self
+    }
+
+    /// Every request received so far, such as `GET /2023/day/1/input`.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+fn handle(stream: TcpStream, state: &Mutex<State>) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+
+    let (mut length, mut cookie) = (0, None);
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() || line.trim_end().is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.trim_end().split_once(':') else {
+            continue;
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => length = value.trim().parse().unwrap_or(0),
+            "cookie" => cookie = Some(value.trim().to_owned()),
+            _ => {}
+        }
+    }
+    let mut body = vec![0; length];
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+
+    let mut words = request_line.split_whitespace();
+    let (method, path) = (words.next().unwrap_or(""), words.next().unwrap_or(""));
+    let mut state = state.lock().unwrap();
+    state.requests.push(format!("{method} {path}"));
+
+    let logged_in = cookie.as_deref() == Some(&format!("session={}", MockServer::SESSION));
+    let route = path
+        .strip_prefix('/')
+        .unwrap_or(path)
+        .split('/')
+        .collect::<Vec<_>>();
+    let (status, body) = match (method, route.as_slice()) {
+        (_, [_, "day", _, _]) if !logged_in => (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".to_owned(),
+        ),
+        ("GET", [year, "day", day, "input"]) => match state.inputs.get(&key(year, day)) {
+            Some(input) => (200, input.clone()),
+            None => (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar \
+                 countdown is synchronized with the server time; the link will be enabled on \
+                 the calendar the instant this puzzle becomes available.\n"
+                    .to_owned(),
+            ),
+        },
+        ("POST", [year, "day", day, "answer"]) => {
+            let (year, day) = key(year, day);
+            (
+                200,
+                judge(&mut state, year, day, &String::from_utf8_lossy(&body)),
+            )
+        }
+        _ => (404, "404 Not Found\n".to_owned()),
+    };
+
+    let mut stream = &stream;
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: \
+         close\r\n\r\n{body}",
+        if status == 200 { "OK" } else { "Error" },
+        body.len()
+    );
+}
+
+fn key(year: &str, day: &str) -> (i32, i32) {
+    (year.parse().unwrap_or(0), day.parse().unwrap_or(0))
+}
+
+/// Builds the page returned after submitting the form in `body`.
+fn judge(state: &mut State, year: i32, day: i32, body: &str) -> String {
+    let field = |name: &str| {
+        body.split('&')
+            .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+            .map(decode)
+            .unwrap_or_default()
+    };
+    let (part, answer) = (field("level").parse().unwrap_or(0), field("answer"));
+
+    if let Some(wait) = state.rate_limit.take() {
+        return format!(
+            "<article><p>You gave an answer too recently; you have to wait after submitting an \
+             answer before trying again.  You have {wait} left to wait.</p></article>"
+        );
+    }
+    let Some(expected) = state.answers.get(&(year, day, part)) else {
+        return "<article><p>You don't seem to be solving the right level.  Did you already \
+                complete it?</p></article>"
+            .to_owned();
+    };
+    if answer == *expected {
+        return "<article><p>That's the right answer!  You are one gold star closer to \
+                saving the world.</p></article>"
+            .to_owned();
+    }
+
+    let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+        (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+        _ => "",
+    };
+    format!(
+        "<article><p>That's not the right answer{hint}.  If you're stuck, make sure you're using \
+         the full input data. (You guessed <span>{answer}</span>.)  Please wait one minute \
+         before trying again.</p></article>"
+    )
+}
+
+/// Decodes a form value, which only needs to handle the characters answers are made of.
+fn decode(value: &str) -> String {
+    let mut decoded = Vec::new();
+    let mut bytes = value.bytes();
+    while let Some(b) = bytes.next() {
+        match b {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let hex = [bytes.next().unwrap_or(b'0'), bytes.next().unwrap_or(b'0')];
+                let hex = std::str::from_utf8(&hex).unwrap_or("00");
+                decoded.push(u8::from_str_radix(hex, 16).unwrap_or(b'?'));
+            }
+            b => decoded.push(b),
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}